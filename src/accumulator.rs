@@ -0,0 +1,218 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::ops::{Div, Sub};
+
+use crate::Zero;
+
+/// Accumulates the worst case error over a stream of pairs of values
+///
+/// This can be used to check the closeness of values which are received
+/// incrementally without needing to store them all.
+///
+/// ```
+/// use isclose::Accumulator;
+///
+/// let mut acc = Accumulator::new();
+/// for i in 1..=100 {
+///     let x = f64::from(i);
+///     acc.push(x.sqrt() * x.sqrt(), x);
+/// }
+///
+/// acc.assert_within(1e-9, 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Accumulator<T> {
+    count: usize,
+    abs_err: T,
+    rel_err: T,
+}
+
+impl<T> Accumulator<T>
+where
+    T: Zero,
+{
+    /// Create a new empty accumulator
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            abs_err: T::ZERO,
+            rel_err: T::ZERO,
+        }
+    }
+}
+
+impl<T> Accumulator<T> {
+    /// The number of pairs of values pushed to the accumulator
+    #[inline]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<T> Default for Accumulator<T>
+where
+    T: Zero,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Accumulator<T>
+where
+    T: Zero + Copy + PartialOrd + Sub<Output = T> + Div<Output = T>,
+{
+    /// Update the accumulated errors with a new pair of values
+    #[inline]
+    pub fn push(&mut self, lhs: impl Borrow<T>, rhs: impl Borrow<T>) {
+        let abs = |x: T| if x < T::ZERO { T::ZERO - x } else { x };
+        // Propagates NaN so it can't be replaced by a later, smaller error
+        #[allow(clippy::eq_op)] // y != y is only true for NaN
+        let max = |x: T, y: T| if x < y || y != y { y } else { x };
+
+        let (lhs, rhs) = (*lhs.borrow(), *rhs.borrow());
+        // Equal infinities have no error rather than a NaN error
+        let abs_err = if lhs == rhs { T::ZERO } else { abs(lhs - rhs) };
+        let scale = max(abs(lhs), abs(rhs));
+        // If the scale is zero both values are zero, so is the error, and if
+        // the scale is NaN so is the error
+        let rel_err = if scale > T::ZERO {
+            abs_err / scale
+        } else {
+            abs_err
+        };
+
+        self.count += 1;
+        self.abs_err = max(self.abs_err, abs_err);
+        self.rel_err = max(self.rel_err, rel_err);
+    }
+
+    /// Return the worst case absolute and relative errors of all the pairs of
+    /// values pushed to the accumulator
+    #[inline]
+    pub fn worst(&self) -> (T, T) {
+        (self.abs_err, self.rel_err)
+    }
+
+    /// Check that every pair of values was within the given relative
+    /// tolerance, or every pair of values was within the given absolute
+    /// tolerance
+    ///
+    /// Since the errors of individual pairs aren't stored this is slightly
+    /// stricter than checking each pair with
+    /// [`IsClose::is_close_tol`][crate::IsClose::is_close_tol]. This returns
+    /// `false` if any pair contained NaN, or an infinity that wasn't equal to
+    /// the other value.
+    #[inline]
+    pub fn is_within(&self, rel_tol: impl Borrow<T>, abs_tol: impl Borrow<T>) -> bool {
+        self.rel_err <= *rel_tol.borrow() || self.abs_err <= *abs_tol.borrow()
+    }
+
+    /// Assert that every pair of values was within the given tolerances, as
+    /// checked by [`Accumulator::is_within`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the accumulated errors are not within the given tolerances.
+    #[inline]
+    #[track_caller]
+    pub fn assert_within(&self, rel_tol: impl Borrow<T>, abs_tol: impl Borrow<T>)
+    where
+        T: Debug,
+    {
+        let (rel_tol, abs_tol) = (rel_tol.borrow(), abs_tol.borrow());
+        assert!(
+            self.is_within(rel_tol, abs_tol),
+            "assertion `accumulated error within tolerance` failed
+   count: {:?}
+ rel err: {:?}
+ abs err: {:?}
+ rel tol: {:?}
+ abs tol: {:?}",
+            self.count,
+            self.rel_err,
+            self.abs_err,
+            rel_tol,
+            abs_tol,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulator_worst() {
+        let mut acc = Accumulator::new();
+        assert_eq!(acc.worst(), (0.0_f64, 0.0));
+
+        acc.push(1.0, 1.0 + 1e-3);
+        acc.push(100.0, 100.0 + 1e-2);
+        acc.push(0.0, 0.0);
+        acc.push(1e-3, 2e-3);
+
+        let (abs_err, rel_err) = acc.worst();
+        assert_eq!(acc.count(), 4);
+        assert!((abs_err - 1e-2).abs() < 1e-12);
+        assert!((rel_err - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn accumulator_assert_within() {
+        let mut acc = Accumulator::new();
+        for i in 1..=1000_u16 {
+            let x = f32::from(i) * 0.1;
+            acc.push(x, x * (1.0 + 1e-7));
+        }
+
+        assert!(acc.is_within(1e-6, 0.0));
+        assert!(acc.is_within(0.0, 1e-4));
+        assert!(!acc.is_within(1e-8, 1e-8));
+        acc.assert_within(1e-6, 1e-6);
+
+        let err = std::panic::catch_unwind(|| acc.assert_within(1e-8, 1e-8)).unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert!(msg.starts_with(
+            "assertion `accumulated error within tolerance` failed
+   count: 1000
+"
+        ));
+    }
+
+    #[test]
+    fn accumulator_nan() {
+        let mut acc = Accumulator::new();
+        acc.push(1.0, f64::NAN);
+        acc.push(1.0, 1.0);
+
+        let (abs_err, rel_err) = acc.worst();
+        assert!(abs_err.is_nan() && rel_err.is_nan());
+        assert!(!acc.is_within(0.0, 0.0));
+        assert!(!acc.is_within(f64::INFINITY, f64::INFINITY));
+
+        let mut acc = Accumulator::new();
+        acc.push(f64::NAN, f64::NAN);
+        assert!(!acc.is_within(f64::INFINITY, f64::INFINITY));
+    }
+
+    #[test]
+    fn accumulator_infinity() {
+        let mut acc = Accumulator::new();
+        acc.push(f64::INFINITY, 1.0);
+        acc.push(1.0, 1.0);
+
+        assert!(!acc.is_within(0.0, 0.0));
+        assert!(!acc.is_within(1.0, 1.0));
+
+        let mut acc = Accumulator::new();
+        acc.push(f64::INFINITY, f64::INFINITY);
+        acc.push(f64::NEG_INFINITY, f64::NEG_INFINITY);
+        assert_eq!(acc.worst(), (0.0, 0.0));
+        assert!(acc.is_within(0.0, 0.0));
+    }
+}
@@ -0,0 +1,67 @@
+use core::borrow::Borrow;
+use core::cell::{Cell, RefCell};
+
+use crate::{IsClose, Zero};
+
+impl<T, Tolerance> IsClose<Tolerance> for Cell<T>
+where
+    T: IsClose<Tolerance> + Copy,
+    Tolerance: Zero,
+{
+    const ABS_TOL: Tolerance = T::ABS_TOL;
+    const REL_TOL: Tolerance = T::REL_TOL;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+    ) -> bool {
+        self.get()
+            .is_close_tol(other.borrow().get(), rel_tol, abs_tol)
+    }
+}
+
+/// Compares the values inside the [`RefCell`]s
+///
+/// # Panics
+///
+/// Panics if either value is currently mutably borrowed.
+impl<T, Tolerance> IsClose<Tolerance> for RefCell<T>
+where
+    T: IsClose<Tolerance>,
+    Tolerance: Zero,
+{
+    const ABS_TOL: Tolerance = T::ABS_TOL;
+    const REL_TOL: Tolerance = T::REL_TOL;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+    ) -> bool {
+        let other: &Self = other.borrow();
+        (*self.borrow()).is_close_tol(&*other.borrow(), rel_tol, abs_tol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_is_close;
+
+    #[test]
+    fn cell_is_close_tol() {
+        assert_is_close!(Cell::new(0.1 + 0.2), Cell::new(0.3));
+        assert!(!Cell::new(0.1_f32).is_close_tol(Cell::new(0.2), 1e-2, 1e-2));
+    }
+
+    #[test]
+    fn ref_cell_is_close_tol() {
+        assert!(RefCell::new(0.1 + 0.2).is_close(RefCell::new(0.3)));
+        assert!(!RefCell::new(0.1_f32).is_close_tol(RefCell::new(0.2), 1e-2, 1e-2));
+    }
+}
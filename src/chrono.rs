@@ -0,0 +1,61 @@
+use core::borrow::Borrow;
+
+use chrono::TimeDelta;
+
+use crate::{Abs, IsClose, TolOrd};
+
+/// Convert a [`TimeDelta`] to seconds, falling back to whole seconds if the
+/// number of nanoseconds overflows an [`i64`]
+#[allow(clippy::cast_precision_loss)] // Only used for tolerance checks, precision loss is ok
+fn as_secs_f64(delta: &TimeDelta) -> f64 {
+    delta
+        .num_nanoseconds()
+        .map_or_else(|| delta.num_seconds() as f64, |nanos| nanos as f64 * 1e-9)
+}
+
+/// Compares the two durations using tolerances given in seconds
+impl IsClose<f64> for TimeDelta {
+    const ABS_TOL: f64 = 1e-9;
+    const REL_TOL: f64 = 1e-9;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<f64>,
+        abs_tol: impl Borrow<f64>,
+    ) -> bool {
+        let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        let (lhs, rhs) = (as_secs_f64(self), as_secs_f64(other));
+        let diff = self
+            .checked_sub(other)
+            .map_or_else(|| lhs - rhs, |diff| as_secs_f64(&diff));
+        let tol = f64::max(Abs::abs(&lhs), Abs::abs(&rhs)) * rel_tol + abs_tol;
+        Abs::abs(&diff).is_within(&tol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_delta_is_close_tol() {
+        let delta1 = TimeDelta::seconds(1);
+        let delta2 = TimeDelta::seconds(1) + TimeDelta::microseconds(3);
+
+        assert!(delta1.is_close_tol(delta2, 0.0, 1e-5));
+        assert!(!delta1.is_close_tol(delta2, 0.0, 1e-6));
+        assert!(delta1.is_close_rel_tol(delta2, 1e-5));
+        assert!(!delta1.is_close(delta2));
+    }
+
+    #[test]
+    fn time_delta_overflow() {
+        let delta1 = TimeDelta::MAX;
+        let delta2 = TimeDelta::MIN;
+
+        assert!(delta1.is_close(delta1 - TimeDelta::microseconds(3)));
+        assert!(!delta1.is_close(delta2));
+    }
+}
@@ -0,0 +1,179 @@
+use core::fmt::Debug;
+
+use crate::{IsClose, Zero};
+
+/// Start a fluent comparison between two values
+///
+/// This is an alternative to the assertion macros for those who prefer method
+/// chaining. Tolerances are set using [`Closeness::rel`] and
+/// [`Closeness::abs`], and the comparison is performed by
+/// [`Closeness::check`] or [`Closeness::assert`]. As with
+/// [`assert_is_close!`][crate::assert_is_close], any tolerance which is not set
+/// is zero, unless neither is set in which case the same default tolerances as
+/// [`assert_is_close!`][crate::assert_is_close] are used.
+///
+/// ```
+/// use isclose::closeness;
+///
+/// closeness(&(0.1 + 0.2), &0.3).assert();
+/// closeness(&1.0, &1.000_001).rel(1e-6).abs(1e-9).assert();
+/// assert!(!closeness(&1.0, &1.1).abs(1e-3).check());
+/// ```
+#[inline]
+pub fn closeness<'a, Value, Tolerance>(
+    lhs: &'a Value,
+    rhs: &'a Value,
+) -> Closeness<'a, Value, Tolerance>
+where
+    Value: IsClose<Tolerance> + ?Sized,
+    Tolerance: Zero,
+{
+    Closeness {
+        lhs,
+        rhs,
+        rel_tol: None,
+        abs_tol: None,
+    }
+}
+
+/// A pending comparison between two values, created by [`closeness`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "a comparison does nothing unless `check` or `assert` is called"]
+pub struct Closeness<'a, Value: ?Sized, Tolerance> {
+    lhs: &'a Value,
+    rhs: &'a Value,
+    rel_tol: Option<Tolerance>,
+    abs_tol: Option<Tolerance>,
+}
+
+impl<Value, Tolerance> Closeness<'_, Value, Tolerance>
+where
+    Value: IsClose<Tolerance> + ?Sized,
+    Tolerance: Zero,
+{
+    /// Set the relative tolerance used for the comparison
+    #[inline]
+    pub fn rel(mut self, rel_tol: Tolerance) -> Self {
+        self.rel_tol = Some(rel_tol);
+        self
+    }
+
+    /// Set the absolute tolerance used for the comparison
+    #[inline]
+    pub fn abs(mut self, abs_tol: Tolerance) -> Self {
+        self.abs_tol = Some(abs_tol);
+        self
+    }
+
+    /// Check if the values are approximately equal
+    #[inline]
+    #[must_use]
+    pub fn check(&self) -> bool {
+        match (&self.rel_tol, &self.abs_tol) {
+            (Some(rel_tol), Some(abs_tol)) => self.lhs.is_close_tol(self.rhs, rel_tol, abs_tol),
+            (Some(rel_tol), None) => self.lhs.is_close_rel_tol(self.rhs, rel_tol),
+            (None, Some(abs_tol)) => self.lhs.is_close_abs_tol(self.rhs, abs_tol),
+            (None, None) => {
+                let (rel_tol, abs_tol) = crate::macros::default_tol(self.lhs);
+                self.lhs.is_close_tol(self.rhs, rel_tol, abs_tol)
+            }
+        }
+    }
+}
+
+impl<Value, Tolerance> Closeness<'_, Value, Tolerance>
+where
+    Value: IsClose<Tolerance> + Debug + ?Sized,
+    Tolerance: Zero + Debug,
+{
+    /// Assert that the values are approximately equal
+    ///
+    /// # Panics
+    ///
+    /// Panics with the same message as
+    /// [`assert_is_close!`][crate::assert_is_close] if the values are not
+    /// approximately equal.
+    #[inline]
+    #[track_caller]
+    pub fn assert(&self) {
+        if !self.check() {
+            if self.rel_tol.is_none() && self.abs_tol.is_none() {
+                let (rel_tol, abs_tol) = crate::macros::default_tol(self.lhs);
+                crate::macros::assert_failed(
+                    self.lhs,
+                    self.rhs,
+                    Some(&rel_tol),
+                    Some(&abs_tol),
+                    None,
+                );
+            }
+            crate::macros::assert_failed(
+                self.lhs,
+                self.rhs,
+                self.rel_tol.as_ref(),
+                self.abs_tol.as_ref(),
+                None,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::is_close;
+
+    fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let err = std::panic::catch_unwind(f).unwrap_err();
+        err.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn check() {
+        let cases = [(1.0_f32, 1.0 + 1e-7), (1.0, 1.1), (1e-9, 2e-9), (0.0, 1e-3)];
+
+        for (lhs, rhs) in cases {
+            assert_eq!(closeness(&lhs, &rhs).check(), is_close!(lhs, rhs));
+            assert_eq!(
+                closeness(&lhs, &rhs).rel(1e-2).check(),
+                is_close!(lhs, rhs, rel_tol = 1e-2)
+            );
+            assert_eq!(
+                closeness(&lhs, &rhs).abs(1e-2).check(),
+                is_close!(lhs, rhs, abs_tol = 1e-2)
+            );
+            assert_eq!(
+                closeness(&lhs, &rhs).rel(1e-2).abs(1e-8).check(),
+                is_close!(lhs, rhs, rel_tol = 1e-2, abs_tol = 1e-8)
+            );
+        }
+    }
+
+    #[test]
+    fn assert() {
+        closeness(&1.0_f64, &(1.0 + 1e-10)).assert();
+        closeness(&1.0_f64, &1.1).rel(0.1).assert();
+        closeness(&1.0_f64, &1.1).abs(0.2).assert();
+        closeness(&1.0_f64, &1.1).rel(1e-3).abs(0.1).assert();
+    }
+
+    #[test]
+    fn assert_error() {
+        assert_eq!(
+            panic_message(|| closeness(&2.0_f32, &3.0).assert()),
+            panic_message(|| crate::assert_is_close!(2.0_f32, 3.0)),
+        );
+        assert_eq!(
+            panic_message(|| closeness(&2.0_f32, &3.0).rel(1e-3).assert()),
+            panic_message(|| crate::assert_is_close_rel_tol!(2.0_f32, 3.0, 1e-3)),
+        );
+        assert_eq!(
+            panic_message(|| closeness(&2.0_f32, &3.0).abs(1e-3).assert()),
+            panic_message(|| crate::assert_is_close_abs_tol!(2.0_f32, 3.0, 1e-3)),
+        );
+        assert_eq!(
+            panic_message(|| closeness(&2.0_f32, &3.0).rel(1e-3).abs(1e-3).assert()),
+            panic_message(|| crate::assert_is_close_tol!(2.0_f32, 3.0, 1e-3, 1e-3)),
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use core::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+const UNSET: u8 = 0;
+const SETTING: u8 = 1;
+const SET: u8 = 2;
+
+/// Bit pattern stored for a tolerance which isn't overridden. This is a NaN
+/// which is never produced by parsing or arithmetic.
+const NO_TOL: u64 = u64::MAX;
+
+/// Default tolerance overrides which can be set at most once
+struct Config {
+    state: AtomicU8,
+    rel_tol: AtomicU64,
+    abs_tol: AtomicU64,
+}
+
+impl Config {
+    const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(UNSET),
+            rel_tol: AtomicU64::new(NO_TOL),
+            abs_tol: AtomicU64::new(NO_TOL),
+        }
+    }
+
+    fn set(&self, rel_tol: Option<f64>, abs_tol: Option<f64>) -> bool {
+        if self
+            .state
+            .compare_exchange(UNSET, SETTING, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            return false;
+        }
+
+        let bits = |tol: Option<f64>| tol.map_or(NO_TOL, f64::to_bits);
+        self.rel_tol.store(bits(rel_tol), Ordering::Relaxed);
+        self.abs_tol.store(bits(abs_tol), Ordering::Relaxed);
+        self.state.store(SET, Ordering::Release);
+        true
+    }
+
+    fn get(&self) -> (Option<f64>, Option<f64>) {
+        if self.state.load(Ordering::Acquire) != SET {
+            return (None, None);
+        }
+
+        let tol = |tol: &AtomicU64| {
+            let bits = tol.load(Ordering::Relaxed);
+            (bits != NO_TOL).then(|| f64::from_bits(bits))
+        };
+        (tol(&self.rel_tol), tol(&self.abs_tol))
+    }
+}
+
+#[cfg(feature = "global-config")]
+static CONFIG: Config = Config::new();
+
+/// Set the default relative and absolute tolerances used by
+/// [`assert_is_close!`][crate::assert_is_close] and similar macros
+///
+/// These are used in place of the type's
+/// [`IsClose::REL_TOL`][crate::IsClose::REL_TOL] and
+/// [`IsClose::ABS_TOL`][crate::IsClose::ABS_TOL] for comparisons using float
+/// tolerances, including [euclid][::euclid]'s types and slices of floats.
+/// Types with other tolerance types, such as tuples, always use their own
+/// defaults.
+///
+/// The tolerances can only be set once; subsequent calls have no effect and
+/// return `false`. They are stored atomically, so this is safe to call from
+/// any thread, but assertions running before it is called will use the type's
+/// defaults. It should therefore be called before the first assertion, for
+/// example at the start of `main` or in a shared test setup function.
+///
+/// If the `env-tol` feature is also enabled, the `ISCLOSE_REL_TOL` and
+/// `ISCLOSE_ABS_TOL` environment variables take precedence over these values.
+///
+/// ```
+/// use isclose::{assert_is_close, set_default_tolerances};
+///
+/// assert!(set_default_tolerances(1e-3, 1e-3));
+/// assert_is_close!(1.0, 1.0005);
+/// assert_is_close!(1.0_f32, 1.0005);
+///
+/// // Only the first call has any effect
+/// assert!(!set_default_tolerances(1e-9, 1e-9));
+/// assert_is_close!(1.0, 1.0005);
+/// ```
+#[cfg(feature = "global-config")]
+#[inline]
+pub fn set_default_tolerances(rel_tol: f64, abs_tol: f64) -> bool {
+    CONFIG.set(Some(rel_tol), Some(abs_tol))
+}
+
+/// Returns the tolerances set using [`set_default_tolerances`], if any
+#[cfg(feature = "global-config")]
+pub fn tol_overrides() -> (Option<f64>, Option<f64>) {
+    CONFIG.get()
+}
+
+/// Returns the tolerances set by the `ISCLOSE_REL_TOL` and `ISCLOSE_ABS_TOL`
+/// environment variables, if any, which are only read on the first call
+#[cfg(feature = "env-tol")]
+pub fn env_tol_overrides() -> (Option<f64>, Option<f64>) {
+    static ENV: Config = Config::new();
+    static INIT: std::sync::Once = std::sync::Once::new();
+
+    INIT.call_once(|| {
+        ENV.set(
+            tol_from_env("ISCLOSE_REL_TOL"),
+            tol_from_env("ISCLOSE_ABS_TOL"),
+        );
+    });
+    ENV.get()
+}
+
+/// Parses a tolerance from an environment variable, warning if it is invalid
+#[cfg(feature = "env-tol")]
+fn tol_from_env(var: &str) -> Option<f64> {
+    let value = std::env::var(var).ok()?;
+    let parsed = value.trim().parse().ok();
+    if parsed.is_none() {
+        eprintln!("warning: ignoring invalid value {value:?} for {var}");
+    }
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config() {
+        let config = Config::new();
+        assert_eq!(config.get(), (None, None));
+
+        assert!(config.set(Some(1e-3), None));
+        assert_eq!(config.get(), (Some(1e-3), None));
+
+        assert!(!config.set(Some(1e-9), Some(1e-9)));
+        assert_eq!(config.get(), (Some(1e-3), None));
+    }
+
+    #[cfg(feature = "env-tol")]
+    #[test]
+    fn env_tol() {
+        let (rel_var, abs_var) = ("ISCLOSE_TEST_REL_TOL", "ISCLOSE_TEST_ABS_TOL");
+        assert_eq!(tol_from_env(rel_var), None);
+
+        std::env::set_var(rel_var, " 0.1 ");
+        std::env::set_var(abs_var, "not a number");
+        assert_eq!(tol_from_env(rel_var), Some(0.1));
+        assert_eq!(tol_from_env(abs_var), None);
+
+        std::env::remove_var(rel_var);
+        std::env::remove_var(abs_var);
+    }
+}
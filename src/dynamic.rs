@@ -0,0 +1,59 @@
+use core::any::Any;
+
+use crate::{IsClose, Zero};
+
+/// Object safe version of [`IsClose`] for comparing trait objects
+///
+/// This is implemented for every `'static` type implementing [`IsClose`] with
+/// itself as the tolerance type. Values of different types are never close.
+///
+/// ```
+/// use isclose::DynIsClose;
+///
+/// let values: [Box<dyn DynIsClose>; 3] = [Box::new(0.1 + 0.2), Box::new(0.3), Box::new(0.3_f32)];
+///
+/// assert!(values[0].dyn_is_close(&*values[1]));
+/// assert!(!values[0].dyn_is_close(&*values[2]));
+/// ```
+pub trait DynIsClose: Any {
+    /// Returns `self` as [`Any`] so it can be downcast to a concrete type
+    fn as_any(&self) -> &dyn Any;
+
+    /// Check if two values are approximately equal using the default
+    /// tolerances, returning `false` if the values have different types
+    fn dyn_is_close(&self, other: &dyn DynIsClose) -> bool;
+}
+
+impl<T> DynIsClose for T
+where
+    T: IsClose + Zero + Any,
+{
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline]
+    fn dyn_is_close(&self, other: &dyn DynIsClose) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .map_or(false, |other| self.is_close(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dyn_is_close() {
+        let lhs: Box<dyn DynIsClose> = Box::new(0.1 + 0.2);
+        let rhs: Box<dyn DynIsClose> = Box::new(0.3);
+
+        assert!(lhs.dyn_is_close(&*rhs));
+        assert!(rhs.dyn_is_close(&*lhs));
+        assert!(!lhs.dyn_is_close(&0.4));
+        assert!(!lhs.dyn_is_close(&0.3_f32));
+    }
+}
@@ -0,0 +1,103 @@
+use core::fmt;
+
+/// The error returned by [`try_assert_is_close!`][crate::try_assert_is_close]
+/// when two values are not approximately equal
+///
+/// This contains the values and tolerances formatted using
+/// [`Debug`][fmt::Debug], and formats the same as the panic message of
+/// [`assert_is_close!`][crate::assert_is_close].
+///
+/// ```
+/// use isclose::{try_assert_is_close, CloseError};
+///
+/// fn check(value: f64) -> Result<(), CloseError> {
+///     try_assert_is_close!(value, 0.3)?;
+///     Ok(())
+/// }
+///
+/// assert!(check(0.1 + 0.2).is_ok());
+///
+/// let err = check(0.4).unwrap_err();
+/// assert_eq!(err.left, "0.4");
+/// assert_eq!(err.right, "0.3");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct CloseError {
+    /// The left hand value
+    pub left: String,
+    /// The right hand value
+    pub right: String,
+    /// The relative tolerance used for the comparison
+    pub rel_tol: String,
+    /// The absolute tolerance used for the comparison
+    pub abs_tol: String,
+    /// The custom message given to the assertion, if any
+    pub message: Option<String>,
+}
+
+impl fmt::Display for CloseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("assertion `left ~= right` failed")?;
+        if let Some(ref message) = self.message {
+            write!(f, ": {message}")?;
+        }
+        write!(
+            f,
+            "
+    left: {}
+   right: {}
+ rel tol: {}
+ abs tol: {}",
+            self.left, self.right, self.rel_tol, self.abs_tol,
+        )
+    }
+}
+
+impl std::error::Error for CloseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::try_assert_is_close;
+
+    #[test]
+    fn close_error() {
+        let err = try_assert_is_close!(2.0_f32, 3.0).unwrap_err();
+
+        assert_eq!(err.left, "2.0");
+        assert_eq!(err.right, "3.0");
+        assert_eq!(err.message, None);
+        assert_eq!(
+            err.to_string(),
+            std::panic::catch_unwind(|| crate::assert_is_close!(2.0_f32, 3.0))
+                .unwrap_err()
+                .downcast_ref::<String>()
+                .unwrap()
+                .as_str(),
+        );
+
+        let err = try_assert_is_close!(2.0_f32, 3.0, "message {}", 1).unwrap_err();
+        assert_eq!(err.message.as_deref(), Some("message 1"));
+        assert!(err
+            .to_string()
+            .starts_with("assertion `left ~= right` failed: message 1\n"));
+
+        assert_eq!(try_assert_is_close!(0.1 + 0.2, 0.3), Ok(()));
+    }
+
+    #[test]
+    fn close_error_boxed() {
+        fn check(value: f64) -> Result<(), Box<dyn std::error::Error>> {
+            try_assert_is_close!(value, 0.3)?;
+            Ok(())
+        }
+
+        assert!(check(0.1 + 0.2).is_ok());
+
+        let err = check(0.4).unwrap_err();
+        let err = err.downcast_ref::<CloseError>().unwrap();
+        assert_eq!(err.left, "0.4");
+        assert_eq!(err.right, "0.3");
+    }
+}
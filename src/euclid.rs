@@ -1,342 +1,1226 @@
+//! Additional comparisons for [euclid]'s geometric types
+
+use core::ops::{Add, Mul, Rem, Sub};
+
 use euclid::{
     Angle, Box2D, Box3D, HomogeneousVector, Length, Point2D, Point3D, Rect, RigidTransform3D,
     Rotation2D, Rotation3D, Scale, SideOffsets2D, Size2D, Size3D, Transform2D, Transform3D,
     Translation2D, Translation3D, Vector2D, Vector3D,
 };
 
-use crate::{IsClose, Zero};
+use num_traits::{Float, FloatConst, One};
+
+use crate::{FrobeniusIsClose, HasZero, IsClose, Zero};
+
+mod scalar {
+    use crate::{IsClose, Zero};
+
+    /// A scalar which is its own tolerance, as used for the components of all
+    /// of euclid's types
+    pub trait ScalarIsClose: IsClose<Self> + Zero + Sized {}
+
+    impl<T> ScalarIsClose for T where T: IsClose<T> + Zero {}
+}
+
+use scalar::ScalarIsClose;
+
+/// Implements [`IsClose`] for a euclid type by comparing each of the given
+/// fields using the same tolerances, forwarding the default tolerances of the
+/// scalar type
+macro_rules! impl_euclid_is_close {
+    ($($ty:ident<T $(, $unit:ident)*> { $($field:tt),+ }),+ $(,)?) => {$(
+        impl<T $(, $unit)*> IsClose<T> for $ty<T $(, $unit)*>
+        where
+            T: ScalarIsClose,
+        {
+            const ABS_TOL: T = T::ABS_TOL;
+            const REL_TOL: T = T::REL_TOL;
+
+            #[inline]
+            fn is_close_tol(
+                &self,
+                other: impl core::borrow::Borrow<Self>,
+                rel_tol: impl core::borrow::Borrow<T>,
+                abs_tol: impl core::borrow::Borrow<T>,
+            ) -> bool {
+                let (other, rel_tol, abs_tol): (&Self, &T, &T) =
+                    (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+                true $(&& self.$field.is_close_tol(&other.$field, rel_tol, abs_tol))+
+            }
+        }
+    )+};
+}
+
+impl_euclid_is_close!(
+    Angle<T> { radians },
+    Box2D<T, U> { min, max },
+    Box3D<T, U> { min, max },
+    HomogeneousVector<T, U> { x, y, z, w },
+    Length<T, U> { 0 },
+    Point2D<T, U> { x, y },
+    Point3D<T, U> { x, y, z },
+    Rect<T, U> { origin, size },
+    RigidTransform3D<T, U1, U2> { rotation, translation },
+    Rotation2D<T, U1, U2> { angle },
+    Rotation3D<T, U1, U2> { i, j, k, r },
+    Scale<T, U1, U2> { 0 },
+    SideOffsets2D<T, U> { top, right, bottom, left },
+    Size2D<T, U> { width, height },
+    Size3D<T, U> { width, height, depth },
+    Transform2D<T, U1, U2> { m11, m12, m21, m22, m31, m32 },
+    Transform3D<T, U1, U2> {
+        m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, m44
+    },
+    Translation2D<T, U1, U2> { x, y },
+    Translation3D<T, U1, U2> { x, y, z },
+    Vector2D<T, U> { x, y },
+    Vector3D<T, U> { x, y, z },
+);
+
+/// Additional comparisons for [`Angle`]
+pub trait AngleIsClose<T> {
+    /// Check if two angles are approximately equal using the given absolute
+    /// tolerance in degrees
+    fn is_close_degrees(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        deg_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if the sines and cosines of two angles are approximately equal
+    /// using the given absolute tolerance
+    ///
+    /// This compares the points on the unit circle corresponding to the
+    /// angles, which is periodic, so angles differing by whole turns (e.g. `0`
+    /// and `2π`) are close. For small differences the tolerance is
+    /// approximately in radians.
+    fn is_close_trig(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
 
-impl<T> IsClose<T> for Angle<T>
+impl<T> AngleIsClose<T> for Angle<T>
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T> + Zero + Float,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
+    #[inline]
+    fn is_close_degrees(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        deg_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        self.is_close_abs_tol(other, deg_tol.borrow().to_radians())
+    }
 
     #[inline]
-    fn is_close_tol(
+    fn is_close_trig(
         &self,
         other: impl core::borrow::Borrow<Self>,
-        rel_tol: impl core::borrow::Borrow<T>,
-        abs_tol: impl core::borrow::Borrow<T>,
+        tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.radians.is_close_tol(&other.radians, rel_tol, abs_tol)
+        let (other, tol): (&Self, &T) = (other.borrow(), tol.borrow());
+        let ((sin1, cos1), (sin2, cos2)) = (self.sin_cos(), other.sin_cos());
+        sin1.is_close_abs_tol(sin2, tol) && cos1.is_close_abs_tol(cos2, tol)
     }
 }
 
-impl<T, U> IsClose<T> for Box2D<T, U>
+/// Additional comparisons for [`Box2D`]
+pub trait Box2DIsClose<T, U> {
+    /// Check if two boxes are approximately equal using the given relative
+    /// and absolute tolerances, scaling the relative tolerance by the length
+    /// of the larger of the two boxes' diagonals
+    ///
+    /// This allows proportionally larger errors in the corners of larger
+    /// boxes, independent of the position of the boxes.
+    fn is_close_by_extent(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if a point is inside the box, or outside it by no more than the
+    /// given tolerance
+    ///
+    /// Unlike [`Box2D::contains`], points on the edges of the box are
+    /// considered contained. This is useful for hit-testing with a margin.
+    fn is_close_contains(
+        &self,
+        point: impl core::borrow::Borrow<Point2D<T, U>>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
+
+impl<T, U> Box2DIsClose<T, U> for Box2D<T, U>
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T> + Zero + Float,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
-
     #[inline]
-    fn is_close_tol(
+    fn is_close_by_extent(
         &self,
         other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.min.is_close_tol(&other.min, rel_tol, abs_tol)
-            && self.max.is_close_tol(&other.max, rel_tol, abs_tol)
+        let other: &Self = other.borrow();
+        let extent = Float::max(
+            (self.max - self.min).length(),
+            (other.max - other.min).length(),
+        );
+        let tol = extent * *rel_tol.borrow() + *abs_tol.borrow();
+        self.is_close_abs_tol(other, tol)
+    }
+
+    #[inline]
+    fn is_close_contains(
+        &self,
+        point: impl core::borrow::Borrow<Point2D<T, U>>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (point, tol): (&Point2D<T, U>, T) = (point.borrow(), *tol.borrow());
+        (self.min.x - tol..=self.max.x + tol).contains(&point.x)
+            && (self.min.y - tol..=self.max.y + tol).contains(&point.y)
     }
 }
 
-impl<T, U> IsClose<T> for Box3D<T, U>
+/// Additional comparisons for [`Box3D`]
+pub trait Box3DIsClose<T, U> {
+    /// Check if two boxes are approximately equal using the given relative
+    /// and absolute tolerances, scaling the relative tolerance by the length
+    /// of the larger of the two boxes' diagonals
+    ///
+    /// This allows proportionally larger errors in the corners of larger
+    /// boxes, independent of the position of the boxes.
+    fn is_close_by_extent(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if the volumes of two boxes are approximately equal using the
+    /// given relative tolerance
+    ///
+    /// Only the size of the boxes is compared; their positions and shapes are
+    /// ignored.
+    fn is_close_volume(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if transforming the corners of the box gives the corners of
+    /// another box, using the given relative and absolute tolerances
+    ///
+    /// Each transformed corner must be close to some corner of `other` and
+    /// vice versa, in any correspondence. Since `other` is axis aligned, this
+    /// can only hold for transforms mapping the box to an axis aligned box,
+    /// e.g. translations, scales, and rotations by multiples of 90°, which is
+    /// useful for testing oriented bounding boxes. Corners which can't be
+    /// transformed, e.g. because they project to infinity, are never close.
+    fn is_close_transformed<V>(
+        &self,
+        other: impl core::borrow::Borrow<Box3D<T, V>>,
+        xform: impl core::borrow::Borrow<Transform3D<T, U, V>>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
+
+/// The eight corners of a box
+fn box_3d_corners<T, U>(bbox: &Box3D<T, U>) -> [Point3D<T, U>; 8]
 where
-    T: IsClose<T> + Zero,
+    T: Copy,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
+    let (min, max) = (bbox.min, bbox.max);
+    [
+        Point3D::new(min.x, min.y, min.z),
+        Point3D::new(max.x, min.y, min.z),
+        Point3D::new(min.x, max.y, min.z),
+        Point3D::new(max.x, max.y, min.z),
+        Point3D::new(min.x, min.y, max.z),
+        Point3D::new(max.x, min.y, max.z),
+        Point3D::new(min.x, max.y, max.z),
+        Point3D::new(max.x, max.y, max.z),
+    ]
+}
 
+impl<T, U> Box3DIsClose<T, U> for Box3D<T, U>
+where
+    T: IsClose<T> + Zero + Float,
+{
     #[inline]
-    fn is_close_tol(
+    fn is_close_by_extent(
         &self,
         other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.min.is_close_tol(&other.min, rel_tol, abs_tol)
-            && self.max.is_close_tol(&other.max, rel_tol, abs_tol)
+        let other: &Self = other.borrow();
+        let extent = Float::max(
+            (self.max - self.min).length(),
+            (other.max - other.min).length(),
+        );
+        let tol = extent * *rel_tol.borrow() + *abs_tol.borrow();
+        self.is_close_abs_tol(other, tol)
     }
-}
-
-impl<T, U> IsClose<T> for HomogeneousVector<T, U>
-where
-    T: IsClose<T> + Zero,
-{
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
 
     #[inline]
-    fn is_close_tol(
+    fn is_close_volume(
         &self,
         other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        self.volume()
+            .is_close_rel_tol(other.borrow().volume(), rel_tol)
+    }
+
+    #[inline]
+    fn is_close_transformed<V>(
+        &self,
+        other: impl core::borrow::Borrow<Box3D<T, V>>,
+        xform: impl core::borrow::Borrow<Transform3D<T, U, V>>,
+        rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.x.is_close_tol(&other.x, rel_tol, abs_tol)
-            && self.y.is_close_tol(&other.y, rel_tol, abs_tol)
-            && self.z.is_close_tol(&other.z, rel_tol, abs_tol)
-            && self.w.is_close_tol(&other.w, rel_tol, abs_tol)
+        let (xform, rel_tol, abs_tol): (&Transform3D<T, U, V>, &T, &T) =
+            (xform.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        let expected = box_3d_corners(other.borrow());
+
+        let mut transformed = [Point3D::origin(); 8];
+        for (corner, point) in transformed.iter_mut().zip(box_3d_corners(self)) {
+            match xform.transform_point3d(point) {
+                Some(point) => *corner = point,
+                None => return false,
+            }
+        }
+
+        let matches = |points: &[Point3D<T, V>; 8], point: &Point3D<T, V>| {
+            points
+                .iter()
+                .any(|p| p.is_close_tol(point, rel_tol, abs_tol))
+        };
+        transformed.iter().all(|p| matches(&expected, p))
+            && expected.iter().all(|p| matches(&transformed, p))
     }
 }
 
-impl<T, U> IsClose<T> for Length<T, U>
+/// Default tolerances for lengths in a given unit
+///
+/// This allows the default tolerances for a [`Length`] to be expressed in its
+/// unit, independently of the tolerances of the underlying scalar type. These
+/// are used by [`LengthIsClose::is_close_unit`].
+///
+/// ```
+/// use euclid::Length;
+/// use isclose::euclid::{LengthIsClose, UnitTolerance};
+///
+/// enum Mm {}
+///
+/// impl UnitTolerance<f64> for Mm {
+///     const ABS_TOL: f64 = 1e-3; // 1µm
+///     const REL_TOL: f64 = 1e-9;
+/// }
+///
+/// assert!(Length::<f64, Mm>::new(0.0).is_close_unit(Length::new(1e-4)));
+/// ```
+pub trait UnitTolerance<T> {
+    /// The default absolute tolerance for lengths in this unit
+    const ABS_TOL: T;
+
+    /// The default relative tolerance for lengths in this unit
+    const REL_TOL: T;
+}
+
+/// Additional comparisons for [`Length`]
+pub trait LengthIsClose<T, U> {
+    /// Check if two lengths in different units are approximately equal using
+    /// the given relative and absolute tolerances, converting `other` to the
+    /// unit of `self` using the given scale before comparing
+    fn is_close_converted<V>(
+        &self,
+        other: impl core::borrow::Borrow<Length<T, V>>,
+        scale: impl core::borrow::Borrow<Scale<T, V, U>>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two lengths are approximately equal using the default
+    /// tolerances of their unit, as given by its [`UnitTolerance`]
+    /// implementation
+    fn is_close_unit(&self, other: impl core::borrow::Borrow<Self>) -> bool
+    where
+        U: UnitTolerance<T>;
+
+    /// Check if the length is approximately equal to the sum of the given
+    /// parts using the given relative and absolute tolerances
+    ///
+    /// This is useful for checking that a total equals the sum of its
+    /// segments, e.g. in layout or measurement code. The parts are summed in
+    /// order, so the tolerances should allow for rounding errors accumulated
+    /// over the sum.
+    fn is_close_sum(
+        &self,
+        parts: &[Length<T, U>],
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool
+    where
+        T: Add<Output = T>;
+}
+
+impl<T, U> LengthIsClose<T, U> for Length<T, U>
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T> + Zero + Copy + Mul<Output = T>,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
-
     #[inline]
-    fn is_close_tol(
+    fn is_close_converted<V>(
         &self,
-        other: impl core::borrow::Borrow<Self>,
+        other: impl core::borrow::Borrow<Length<T, V>>,
+        scale: impl core::borrow::Borrow<Scale<T, V, U>>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.0.is_close_tol(&other.0, rel_tol, abs_tol)
+        let other = *other.borrow() * *scale.borrow();
+        self.is_close_tol(other, rel_tol, abs_tol)
+    }
+
+    #[inline]
+    fn is_close_unit(&self, other: impl core::borrow::Borrow<Self>) -> bool
+    where
+        U: UnitTolerance<T>,
+    {
+        self.is_close_tol(other, U::REL_TOL, U::ABS_TOL)
+    }
+
+    #[inline]
+    fn is_close_sum(
+        &self,
+        parts: &[Self],
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool
+    where
+        T: Add<Output = T>,
+    {
+        let sum = parts.iter().fold(T::ZERO, |sum, part| sum + part.get());
+        self.get().is_close_tol(sum, rel_tol, abs_tol)
     }
 }
 
-impl<T, U> IsClose<T> for Point2D<T, U>
+/// Additional comparisons for [`Point2D`]
+pub trait Point2DIsClose<T, U> {
+    /// Check if two points are approximately equal using the given relative
+    /// and absolute tolerances, scaling the relative tolerance by the length
+    /// of the diagonal of the given bounding box
+    ///
+    /// This makes the comparison independent of the position of the points,
+    /// comparing them relative to the scale of the geometry instead.
+    fn is_close_in_box(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        bbox: impl core::borrow::Borrow<Box2D<T, U>>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if the Manhattan (L1) distance between two points is within the
+    /// given tolerance
+    ///
+    /// Unlike the default comparison, which checks each component
+    /// independently, this sums the absolute differences of the components.
+    fn is_close_manhattan(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two points snap to the same point on a grid with the given
+    /// spacing
+    ///
+    /// Each coordinate is rounded to the nearest multiple of `grid` before
+    /// comparing exactly, so points in the same grid cell are equal
+    /// regardless of their distance apart, and points either side of a cell
+    /// boundary are not, however close they are.
+    fn is_close_snapped(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        grid: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two points are equal after snapping them to a grid with the
+    /// given spacing, only snapping coordinates within `snap_tol` of a
+    /// multiple of `grid`
+    ///
+    /// Coordinates further than `snap_tol` from the grid are left unsnapped
+    /// and must be exactly equal. With a `snap_tol` of at least half of
+    /// `grid`, this is equivalent to [`Point2DIsClose::is_close_snapped`].
+    fn is_close_snapped_tol(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        grid: impl core::borrow::Borrow<T>,
+        snap_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if the point lies on the infinite line through `p0` and `p1`,
+    /// within the given tolerance of its perpendicular distance to the line
+    ///
+    /// The point may lie anywhere along the line, not just on the segment
+    /// between `p0` and `p1`. If `p0` and `p1` are equal the line is
+    /// undefined, so the point is compared to `p0` instead.
+    fn is_close_on_line(
+        &self,
+        p0: impl core::borrow::Borrow<Self>,
+        p1: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
+
+impl<T, U> Point2DIsClose<T, U> for Point2D<T, U>
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T> + Zero + Float,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
-
     #[inline]
-    fn is_close_tol(
+    fn is_close_in_box(
         &self,
         other: impl core::borrow::Borrow<Self>,
+        bbox: impl core::borrow::Borrow<Box2D<T, U>>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.x.is_close_tol(&other.x, rel_tol, abs_tol)
-            && self.y.is_close_tol(&other.y, rel_tol, abs_tol)
+        let (other, bbox): (&Self, &Box2D<T, U>) = (other.borrow(), bbox.borrow());
+        let diagonal = (bbox.max - bbox.min).length();
+        let tol = diagonal * *rel_tol.borrow() + *abs_tol.borrow();
+        self.is_close_abs_tol(other, tol)
+    }
+
+    #[inline]
+    fn is_close_manhattan(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let diff = *self - *other.borrow();
+        Float::abs(diff.x) + Float::abs(diff.y) <= *tol.borrow()
+    }
+
+    #[inline]
+    fn is_close_snapped(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        grid: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (other, grid): (&Self, T) = (other.borrow(), *grid.borrow());
+        let snap = |x: T| (x / grid).round();
+        snap(self.x) == snap(other.x) && snap(self.y) == snap(other.y)
+    }
+
+    #[inline]
+    fn is_close_snapped_tol(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        grid: impl core::borrow::Borrow<T>,
+        snap_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (grid, snap_tol) = (*grid.borrow(), *snap_tol.borrow());
+        let snap = |x: T| {
+            let snapped = (x / grid).round() * grid;
+            if Float::abs(x - snapped) <= snap_tol {
+                snapped
+            } else {
+                x
+            }
+        };
+        let other: &Self = other.borrow();
+        snap(self.x) == snap(other.x) && snap(self.y) == snap(other.y)
+    }
+
+    #[inline]
+    fn is_close_on_line(
+        &self,
+        p0: impl core::borrow::Borrow<Self>,
+        p1: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (p0, tol): (&Self, T) = (p0.borrow(), *tol.borrow());
+        let (dir, offset) = (*p1.borrow() - *p0, *self - *p0);
+        let length = dir.length();
+        if length == T::zero() {
+            offset.length() <= tol
+        } else {
+            Float::abs(dir.cross(offset)) <= tol * length
+        }
     }
 }
 
-impl<T, U> IsClose<T> for Point3D<T, U>
+/// Check if two polygons are approximately equal using the given relative and
+/// absolute tolerances, regardless of which vertex they start at or which
+/// direction they wind in
+///
+/// Every rotation of the vertices of `rhs`, in both orientations, is compared
+/// against `lhs` until a matching alignment is found, so this takes quadratic
+/// time in the number of vertices. Polygons with different numbers of vertices
+/// are never close.
+///
+/// ```
+/// use euclid::default::Point2D;
+/// use isclose::euclid::polygon_is_close;
+///
+/// let square = [
+///     Point2D::new(0.0, 0.0),
+///     Point2D::new(1.0, 0.0),
+///     Point2D::new(1.0, 1.0),
+///     Point2D::new(0.0, 1.0),
+/// ];
+/// let rotated = [square[2], square[3], square[0], square[1]];
+///
+/// assert!(polygon_is_close(&square, &rotated, 1e-9, 0.0));
+/// ```
+#[inline]
+pub fn polygon_is_close<T, U>(
+    lhs: &[Point2D<T, U>],
+    rhs: &[Point2D<T, U>],
+    rel_tol: impl core::borrow::Borrow<T>,
+    abs_tol: impl core::borrow::Borrow<T>,
+) -> bool
 where
     T: IsClose<T> + Zero,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
+    let (rel_tol, abs_tol) = (rel_tol.borrow(), abs_tol.borrow());
+    let n = lhs.len();
+    if n != rhs.len() {
+        return false;
+    }
+    if n == 0 {
+        return true;
+    }
 
-    #[inline]
-    fn is_close_tol(
+    (0..n).any(|start| {
+        let forward = (0..n).all(|i| lhs[i].is_close_tol(&rhs[(start + i) % n], rel_tol, abs_tol));
+        let reverse =
+            || (0..n).all(|i| lhs[i].is_close_tol(&rhs[(start + n - i) % n], rel_tol, abs_tol));
+        forward || reverse()
+    })
+}
+
+/// Additional comparisons for [`Point3D`]
+pub trait Point3DIsClose<T, U> {
+    /// Check if two points are approximately equal using the given relative
+    /// and absolute tolerances after projecting them onto the plane with the
+    /// given normal, ignoring any difference along the normal
+    ///
+    /// The normal doesn't need to be normalized. If it has zero length there
+    /// is no plane, so the points are compared directly.
+    fn is_close_on_plane(
         &self,
         other: impl core::borrow::Borrow<Self>,
+        normal: impl core::borrow::Borrow<Vector3D<T, U>>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
-    ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.x.is_close_tol(&other.x, rel_tol, abs_tol)
-            && self.y.is_close_tol(&other.y, rel_tol, abs_tol)
-            && self.z.is_close_tol(&other.z, rel_tol, abs_tol)
-    }
+    ) -> bool;
+
+    /// Check if two points have approximately equal barycentric coordinates
+    /// relative to the given triangle, using the given absolute tolerance
+    ///
+    /// Barycentric coordinates are relative to the size of the triangle, so
+    /// the tolerance is independent of the scale of the mesh. Points off the
+    /// plane of the triangle are projected onto it. Points are never close
+    /// relative to a degenerate triangle.
+    fn is_close_barycentric(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tri: impl core::borrow::Borrow<[Point3D<T, U>; 3]>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
 }
 
-impl<T, U> IsClose<T> for Rect<T, U>
+/// Computes the barycentric coordinates of a point relative to a triangle
+fn barycentric<T, U>(point: Point3D<T, U>, tri: [Point3D<T, U>; 3]) -> [T; 3]
 where
-    T: IsClose<T> + Zero,
+    T: Float,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
+    let (edge1, edge2, offset) = (tri[1] - tri[0], tri[2] - tri[0], point - tri[0]);
+    let (len1, len2, dot) = (
+        edge1.square_length(),
+        edge2.square_length(),
+        edge1.dot(edge2),
+    );
+    let (proj1, proj2) = (offset.dot(edge1), offset.dot(edge2));
+    let denom = edge1.cross(edge2).square_length();
+    let weight1 = (len2 * proj1 - dot * proj2) / denom;
+    let weight2 = (len1 * proj2 - dot * proj1) / denom;
+    [T::one() - weight1 - weight2, weight1, weight2]
+}
 
+impl<T, U> Point3DIsClose<T, U> for Point3D<T, U>
+where
+    T: IsClose<T> + Zero + Float,
+{
     #[inline]
-    fn is_close_tol(
+    fn is_close_on_plane(
         &self,
         other: impl core::borrow::Borrow<Self>,
+        normal: impl core::borrow::Borrow<Vector3D<T, U>>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.origin.is_close_tol(&other.origin, rel_tol, abs_tol)
-            && self.size.is_close_tol(&other.size, rel_tol, abs_tol)
+        let (other, normal): (&Self, &Vector3D<T, U>) = (other.borrow(), normal.borrow());
+        if normal.square_length() == T::zero() {
+            return self.is_close_tol(other, rel_tol, abs_tol);
+        }
+        let project = |point: Self| {
+            let vec = point.to_vector();
+            point - *normal * (vec.dot(*normal) / normal.square_length())
+        };
+        project(*self).is_close_tol(project(*other), rel_tol, abs_tol)
+    }
+
+    #[inline]
+    fn is_close_barycentric(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tri: impl core::borrow::Borrow<[Self; 3]>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (tri, tol) = (*tri.borrow(), tol.borrow());
+        let (lhs, rhs) = (barycentric(*self, tri), barycentric(*other.borrow(), tri));
+        lhs.iter()
+            .zip(rhs.iter())
+            .all(|(lhs, rhs)| lhs.is_close_abs_tol(rhs, tol))
     }
 }
 
-impl<T, U1, U2> IsClose<T> for RigidTransform3D<T, U1, U2>
+/// Additional comparisons for [`Rect`]
+pub trait RectIsClose<T> {
+    /// Check if the intersection over union (`IoU`) of two rects is at least
+    /// the given minimum
+    ///
+    /// The `IoU` is the area of the intersection of the rects divided by the
+    /// area of their union, ranging from zero for disjoint rects to one for
+    /// identical rects. This is commonly used to compare bounding boxes, e.g.
+    /// in object detection. Rects with zero area have no `IoU`, so are never
+    /// considered close.
+    fn is_close_iou(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        min_iou: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
+
+impl<T, U> RectIsClose<T> for Rect<T, U>
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T> + Zero + Float,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
-
     #[inline]
-    fn is_close_tol(
+    fn is_close_iou(
         &self,
         other: impl core::borrow::Borrow<Self>,
-        rel_tol: impl core::borrow::Borrow<T>,
-        abs_tol: impl core::borrow::Borrow<T>,
+        min_iou: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.rotation
-            .is_close_tol(&other.rotation, rel_tol, abs_tol)
-            && self
-                .translation
-                .is_close_tol(&other.translation, rel_tol, abs_tol)
+        let other: &Self = other.borrow();
+        let intersection = self
+            .intersection(other)
+            .map_or_else(T::zero, |rect| rect.area());
+        let union = self.area() + other.area() - intersection;
+        union > T::zero() && intersection / union >= *min_iou.borrow()
     }
 }
 
-impl<T, U1, U2> IsClose<T> for Rotation2D<T, U1, U2>
+/// Additional comparisons for [`Rotation2D`]
+pub trait Rotation2DIsClose<T> {
+    /// Check if two rotations are approximately equal using the given
+    /// relative and absolute tolerances, ignoring any difference of a whole
+    /// number of turns
+    ///
+    /// The angle of `other` is first wrapped to within half a turn of the
+    /// angle of `self`, so e.g. rotations by `0` and `2π` are close.
+    fn is_close_wrapped(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two rotations are approximately equal using the given
+    /// relative and absolute tolerances, by applying both to the given vector
+    /// and comparing the results
+    ///
+    /// This sidesteps angle wraparound entirely, so e.g. rotations by `0` and
+    /// `2π` are close. The tolerances are in the units of the vector.
+    fn is_close_on_vector<U>(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        vector: impl core::borrow::Borrow<Vector2D<T, U>>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool
+    where
+        T: Float;
+}
+
+impl<T, U1, U2> Rotation2DIsClose<T> for Rotation2D<T, U1, U2>
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T>
+        + Zero
+        + Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Rem<Output = T>
+        + One
+        + FloatConst,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
-
     #[inline]
-    fn is_close_tol(
+    fn is_close_wrapped(
         &self,
         other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.angle.is_close_tol(&other.angle, rel_tol, abs_tol)
+        let (angle, other) = (
+            Angle::radians(self.angle),
+            Angle::radians(other.borrow().angle),
+        );
+        angle.is_close_tol(angle + angle.angle_to(other), rel_tol, abs_tol)
+    }
+
+    #[inline]
+    fn is_close_on_vector<U>(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        vector: impl core::borrow::Borrow<Vector2D<T, U>>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool
+    where
+        T: Float,
+    {
+        let (other, vector): (&Self, &Vector2D<T, U>) = (other.borrow(), vector.borrow());
+        let rotate = |angle: T| Rotation2D::<T, U, U>::radians(angle).transform_vector(*vector);
+        rotate(self.angle).is_close_tol(rotate(other.angle), rel_tol, abs_tol)
     }
 }
 
-impl<T, U1, U2> IsClose<T> for Rotation3D<T, U1, U2>
+/// Additional comparisons for [`Rotation3D`]
+pub trait Rotation3DIsClose<T> {
+    /// Check if the angle of the rotation between two rotations is within the
+    /// given absolute tolerance in radians
+    ///
+    /// This measures how different the two orientations are, so the
+    /// quaternions `q` and `-q` are considered equal.
+    fn is_close_angle(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        angle_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if the rotation matrix of the quaternion is orthonormal within
+    /// the given absolute tolerance, i.e. if it is a valid rotation
+    ///
+    /// See [`Transform3DIsClose::is_close_orthonormal`]. This is equivalent to
+    /// checking that the quaternion has unit length.
+    fn is_close_orthonormal(&self, tol: impl core::borrow::Borrow<T>) -> bool;
+
+    /// Check if the Euler angles of two rotations in the given order are
+    /// within the given absolute tolerance in radians
+    ///
+    /// Both rotations are decomposed into Euler angles `(α, β, γ)` with `β`
+    /// in `[-π/2, π/2]`, which is unique except in gimbal lock, and the angles
+    /// are compared pairwise with `α` and `γ` wrapped to within half a turn.
+    /// Equivalent triples therefore compare equal, e.g. `(α, β, γ)` and
+    /// `(α + π, π - β, γ + π)`. If either rotation is within the tolerance of
+    /// gimbal lock, where `α` and `γ` aren't unique, the rotations are instead
+    /// compared using [`Rotation3DIsClose::is_close_angle`].
+    fn is_close_euler(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        order: EulerOrder,
+        angle_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
+
+/// The order in which Euler angle rotations are applied, as used by
+/// [`Rotation3DIsClose::is_close_euler`]
+///
+/// The angles `(α, β, γ)` are rotations about the given axes in order, each
+/// about the fixed (extrinsic) axes. For example [`EulerOrder::Xyz`] rotates
+/// by `α` about the x axis, then by `β` about the y axis, then by `γ` about
+/// the z axis. This is equivalent to applying the rotations in the reverse
+/// order about the rotating (intrinsic) axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    /// Rotate about x, then y, then z
+    Xyz,
+    /// Rotate about x, then z, then y
+    Xzy,
+    /// Rotate about y, then x, then z
+    Yxz,
+    /// Rotate about y, then z, then x
+    Yzx,
+    /// Rotate about z, then x, then y
+    Zxy,
+    /// Rotate about z, then y, then x
+    Zyx,
+}
+
+impl EulerOrder {
+    /// The indices of the axes in order, and the parity of the permutation
+    const fn axes(self) -> ([usize; 3], bool) {
+        match self {
+            Self::Xyz => ([0, 1, 2], true),
+            Self::Yzx => ([1, 2, 0], true),
+            Self::Zxy => ([2, 0, 1], true),
+            Self::Xzy => ([0, 2, 1], false),
+            Self::Yxz => ([1, 0, 2], false),
+            Self::Zyx => ([2, 1, 0], false),
+        }
+    }
+}
+
+/// Decomposes a rotation into Euler angles in the given order, also returning
+/// the cosine of the middle angle, which is zero in gimbal lock
+fn euler_angles<T, U1, U2>(rot: &Rotation3D<T, U1, U2>, order: EulerOrder) -> ([T; 3], T)
 where
-    T: IsClose<T> + Zero,
+    T: Float,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
+    let norm = (rot.i * rot.i + rot.j * rot.j + rot.k * rot.k + rot.r * rot.r).sqrt();
+    let (i, j, k, r) = (rot.i / norm, rot.j / norm, rot.k / norm, rot.r / norm);
+    let (one, two) = (T::one(), T::one() + T::one());
+
+    // Rotation matrix, transforming column vectors
+    let matrix = [
+        [
+            one - two * (j * j + k * k),
+            two * (i * j - r * k),
+            two * (i * k + r * j),
+        ],
+        [
+            two * (i * j + r * k),
+            one - two * (i * i + k * k),
+            two * (j * k - r * i),
+        ],
+        [
+            two * (i * k - r * j),
+            two * (j * k + r * i),
+            one - two * (i * i + j * j),
+        ],
+    ];
+
+    let ([first, second, third], even) = order.axes();
+    let sign = if even { one } else { -one };
+    let row = matrix[third];
+    let sin_beta = (-sign * row[first]).max(-one).min(one);
+    let cos_beta = (row[second] * row[second] + row[third] * row[third]).sqrt();
+    let alpha = (sign * row[second]).atan2(row[third]);
+    let beta = sin_beta.atan2(cos_beta);
+    let gamma = (sign * matrix[second][first]).atan2(matrix[first][first]);
+    ([alpha, beta, gamma], cos_beta)
+}
 
+impl<T, U1, U2> Rotation3DIsClose<T> for Rotation3D<T, U1, U2>
+where
+    T: IsClose<T> + Zero + Float,
+{
     #[inline]
-    fn is_close_tol(
+    fn is_close_angle(
         &self,
         other: impl core::borrow::Borrow<Self>,
-        rel_tol: impl core::borrow::Borrow<T>,
-        abs_tol: impl core::borrow::Borrow<T>,
+        angle_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.i.is_close_tol(&other.i, rel_tol, abs_tol)
-            && self.j.is_close_tol(&other.j, rel_tol, abs_tol)
-            && self.k.is_close_tol(&other.k, rel_tol, abs_tol)
-            && self.r.is_close_tol(&other.r, rel_tol, abs_tol)
+        let other: &Self = other.borrow();
+        let lhs = [self.i, self.j, self.k, self.r];
+        let rhs = [other.i, other.j, other.k, other.r];
+
+        // Align the signs of the quaternions to handle the double cover
+        let dot = (0..4).fold(T::zero(), |acc, i| acc + lhs[i] * rhs[i]);
+        let sign = if dot < T::zero() { -T::one() } else { T::one() };
+
+        // For unit quaternions |q1 - q2| and |q1 + q2| are 2sin(θ/4) and
+        // 2cos(θ/4), which is more accurate for small angles than using acos
+        let (diff, sum) = (0..4).fold((T::zero(), T::zero()), |(diff, sum), i| {
+            let (d, s) = (lhs[i] - sign * rhs[i], lhs[i] + sign * rhs[i]);
+            (diff + d * d, sum + s * s)
+        });
+        let four = T::from(4).unwrap_or_else(T::nan);
+        let angle = four * diff.sqrt().atan2(sum.sqrt());
+        angle <= *angle_tol.borrow()
+    }
+
+    #[inline]
+    fn is_close_orthonormal(&self, tol: impl core::borrow::Borrow<T>) -> bool {
+        // Same as Rotation3D::to_transform, which can't be used since it
+        // asserts that the quaternion is normalized
+        let (i2, j2, k2) = (self.i + self.i, self.j + self.j, self.k + self.k);
+        let (ii, ij, ik) = (self.i * i2, self.i * j2, self.i * k2);
+        let (jj, jk, kk) = (self.j * j2, self.j * k2, self.k * k2);
+        let (ri, rj, rk) = (self.r * i2, self.r * j2, self.r * k2);
+        let one = T::one();
+
+        let rows = [
+            Vector3D::<T, U1>::new(one - (jj + kk), ij + rk, ik - rj),
+            Vector3D::new(ij - rk, one - (ii + kk), jk + ri),
+            Vector3D::new(ik + rj, jk - ri, one - (ii + jj)),
+        ];
+        is_close_orthonormal_basis(rows, tol)
+    }
+
+    #[inline]
+    fn is_close_euler(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        order: EulerOrder,
+        angle_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (other, angle_tol): (&Self, T) = (other.borrow(), *angle_tol.borrow());
+        let (lhs, lhs_cos) = euler_angles(self, order);
+        let (rhs, rhs_cos) = euler_angles(other, order);
+
+        // cos(β) is the sine of the distance to gimbal lock
+        if lhs_cos <= angle_tol.sin() || rhs_cos <= angle_tol.sin() {
+            return self.is_close_angle(other, angle_tol);
+        }
+
+        let wrapped = |lhs: T, rhs: T| {
+            let diff = lhs - rhs;
+            diff.sin().atan2(diff.cos()).abs() <= angle_tol
+        };
+        wrapped(lhs[0], rhs[0]) && (lhs[1] - rhs[1]).abs() <= angle_tol && wrapped(lhs[2], rhs[2])
     }
 }
 
-impl<T, U1, U2> IsClose<T> for Scale<T, U1, U2>
+/// Additional comparisons for [`Scale`]
+pub trait ScaleIsClose {
+    /// Check if the scale factor is approximately equal to one using the
+    /// default tolerances
+    fn is_close_identity(&self) -> bool;
+}
+
+impl<T, U1, U2> ScaleIsClose for Scale<T, U1, U2>
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T> + Zero + One,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
-
     #[inline]
-    fn is_close_tol(
+    fn is_close_identity(&self) -> bool {
+        self.0.is_close(T::one())
+    }
+}
+
+/// Additional comparisons for [`SideOffsets2D`]
+pub trait SideOffsets2DIsClose<T> {
+    /// Check if all four offsets are approximately equal to each other using
+    /// the given relative and absolute tolerances, i.e. if the offsets are
+    /// approximately uniform
+    fn is_close_uniform(
         &self,
-        other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
-    ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.0.is_close_tol(&other.0, rel_tol, abs_tol)
-    }
+    ) -> bool;
 }
 
-impl<T, U> IsClose<T> for SideOffsets2D<T, U>
+impl<T, U> SideOffsets2DIsClose<T> for SideOffsets2D<T, U>
 where
     T: IsClose<T> + Zero,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
-
     #[inline]
-    fn is_close_tol(
+    fn is_close_uniform(
         &self,
-        other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.top.is_close_tol(&other.top, rel_tol, abs_tol)
-            && self.right.is_close_tol(&other.right, rel_tol, abs_tol)
-            && self.bottom.is_close_tol(&other.bottom, rel_tol, abs_tol)
-            && self.left.is_close_tol(&other.left, rel_tol, abs_tol)
+        let (rel_tol, abs_tol): (&T, &T) = (rel_tol.borrow(), abs_tol.borrow());
+        self.top.is_close_tol(&self.right, rel_tol, abs_tol)
+            && self.top.is_close_tol(&self.bottom, rel_tol, abs_tol)
+            && self.top.is_close_tol(&self.left, rel_tol, abs_tol)
     }
 }
 
-impl<T, U> IsClose<T> for Size2D<T, U>
+/// Additional comparisons for [`Size2D`]
+pub trait Size2DIsClose {
+    /// Check if the areas of two sizes are approximately equal using the given
+    /// relative tolerance, regardless of their dimensions
+    ///
+    /// The areas are computed as [`f64`], so sizes with integer components
+    /// can be compared without overflowing.
+    fn is_close_area(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<f64>,
+    ) -> bool;
+}
+
+impl<T, U> Size2DIsClose for Size2D<T, U>
 where
-    T: IsClose<T> + Zero,
+    T: Copy + Into<f64>,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
-
     #[inline]
-    fn is_close_tol(
+    fn is_close_area(
         &self,
         other: impl core::borrow::Borrow<Self>,
-        rel_tol: impl core::borrow::Borrow<T>,
-        abs_tol: impl core::borrow::Borrow<T>,
+        rel_tol: impl core::borrow::Borrow<f64>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.width.is_close_tol(&other.width, rel_tol, abs_tol)
-            && self.height.is_close_tol(&other.height, rel_tol, abs_tol)
+        let area = |size: &Self| size.width.into() * size.height.into();
+        area(self).is_close_rel_tol(area(other.borrow()), rel_tol)
     }
 }
 
-impl<T, U> IsClose<T> for Size3D<T, U>
+/// Additional comparisons for [`Transform2D`]
+pub trait Transform2DIsClose<T, U1, U2> {
+    /// Check if the transform is approximately equal to the identity transform
+    /// using the default tolerances
+    fn is_close_identity(&self) -> bool;
+
+    /// Check if the transform is approximately the inverse of `other` using
+    /// the given relative and absolute tolerances, i.e. if applying both
+    /// transforms is approximately equal to the identity transform
+    fn is_close_inverse_of(
+        &self,
+        other: impl core::borrow::Borrow<Transform2D<T, U2, U1>>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two transforms map each of the given points to approximately
+    /// equal points using the given relative and absolute tolerances
+    ///
+    /// This only compares the transforms on the geometry of interest, so
+    /// transforms with different matrices can be close if they agree on every
+    /// point, e.g. a shear along an axis for points on that axis.
+    fn is_close_on_points(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        points: &[Point2D<T, U1>],
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if the determinants of the linear parts of two transforms are
+    /// approximately equal using the given relative tolerance
+    ///
+    /// The determinant is the factor by which the transform scales areas, and
+    /// is negative if it flips orientation. This only compares the area
+    /// scaling, so e.g. transforms with different rotations, shears or
+    /// translations can be close.
+    fn is_close_determinant(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
+
+impl<T, U1, U2> Transform2DIsClose<T, U1, U2> for Transform2D<T, U1, U2>
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T> + Zero + Float,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
+    #[inline]
+    fn is_close_identity(&self) -> bool {
+        self.is_close(Self::identity())
+    }
 
     #[inline]
-    fn is_close_tol(
+    fn is_close_inverse_of(
+        &self,
+        other: impl core::borrow::Borrow<Transform2D<T, U2, U1>>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        self.then(other.borrow())
+            .is_close_tol(Transform2D::identity(), rel_tol, abs_tol)
+    }
+
+    #[inline]
+    fn is_close_on_points(
         &self,
         other: impl core::borrow::Borrow<Self>,
+        points: &[Point2D<T, U1>],
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
         let (other, rel_tol, abs_tol): (&Self, &T, &T) =
             (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.width.is_close_tol(&other.width, rel_tol, abs_tol)
-            && self.height.is_close_tol(&other.height, rel_tol, abs_tol)
-            && self.depth.is_close_tol(&other.depth, rel_tol, abs_tol)
+        points.iter().all(|&point| {
+            self.transform_point(point)
+                .is_close_tol(other.transform_point(point), rel_tol, abs_tol)
+        })
+    }
+
+    #[inline]
+    fn is_close_determinant(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        self.determinant()
+            .is_close_rel_tol(other.borrow().determinant(), rel_tol)
     }
 }
 
-impl<T, U1, U2> IsClose<T> for Transform2D<T, U1, U2>
+/// Additional comparisons for [`Transform3D`]
+pub trait Transform3DIsClose<T> {
+    /// Check if two affine transforms are approximately equal using the given
+    /// relative and absolute tolerances, by decomposing them into their
+    /// translation, rotation and scale and comparing those instead of the
+    /// individual matrix entries
+    ///
+    /// The rotations are compared as quaternions, so rotations `q` and `-q`
+    /// are considered equal. The transforms are assumed to be scaled then
+    /// rotated then translated. Any projective components are compared
+    /// directly.
+    fn is_close_decomposed(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if the transform is approximately equal to the identity transform
+    /// using the default tolerances
+    fn is_close_identity(&self) -> bool;
+
+    /// Check if the upper-left 3x3 submatrix of the transform is orthonormal
+    /// within the given absolute tolerance
+    ///
+    /// The rows must have unit length and be mutually perpendicular, i.e. the
+    /// transform must be a rotation without any scale or shear. Note this also
+    /// holds for reflections. The translation and projective components are
+    /// ignored.
+    fn is_close_orthonormal(&self, tol: impl core::borrow::Borrow<T>) -> bool;
+
+    /// Check if two projective transforms are approximately equal up to scale
+    /// using the given relative and absolute tolerances
+    ///
+    /// Both matrices are divided by their Frobenius norm before their entries
+    /// are compared, so any nonzero scalar multiple of a matrix (including a
+    /// negative one) is considered equal to it. The tolerances therefore apply
+    /// to the normalized entries. The zero matrix is never close to anything.
+    fn is_close_projective(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if the translation components of two affine transforms are
+    /// approximately equal using the given relative and absolute tolerances,
+    /// ignoring any rotation, scale or shear
+    ///
+    /// The translation is the bottom row of the matrix, i.e. where the
+    /// transforms map the origin to. Any projective components are ignored.
+    fn is_close_translation(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
+
+impl<T, U1, U2> Transform3DIsClose<T> for Transform3D<T, U1, U2>
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T> + Zero + Float,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
-
     #[inline]
-    fn is_close_tol(
+    fn is_close_decomposed(
         &self,
         other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
@@ -344,24 +1228,40 @@ where
     ) -> bool {
         let (other, rel_tol, abs_tol): (&Self, &T, &T) =
             (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.m11.is_close_tol(&other.m11, rel_tol, abs_tol)
-            && self.m12.is_close_tol(&other.m12, rel_tol, abs_tol)
-            && self.m21.is_close_tol(&other.m21, rel_tol, abs_tol)
-            && self.m22.is_close_tol(&other.m22, rel_tol, abs_tol)
-            && self.m31.is_close_tol(&other.m31, rel_tol, abs_tol)
-            && self.m32.is_close_tol(&other.m32, rel_tol, abs_tol)
+
+        let (lhs, rhs) = (Decomposed::new(self), Decomposed::new(other));
+        let rot = rhs.rotation;
+        let neg_rot = Rotation3D::quaternion(-rot.i, -rot.j, -rot.k, -rot.r);
+
+        let projective1 = HomogeneousVector::<T, U1>::new(self.m14, self.m24, self.m34, self.m44);
+        let projective2 =
+            HomogeneousVector::<T, U1>::new(other.m14, other.m24, other.m34, other.m44);
+
+        lhs.translation
+            .is_close_tol(rhs.translation, rel_tol, abs_tol)
+            && lhs.scale.is_close_tol(rhs.scale, rel_tol, abs_tol)
+            && (lhs.rotation.is_close_tol(rot, rel_tol, abs_tol)
+                || lhs.rotation.is_close_tol(neg_rot, rel_tol, abs_tol))
+            && projective1.is_close_tol(projective2, rel_tol, abs_tol)
     }
-}
 
-impl<T, U1, U2> IsClose<T> for Transform3D<T, U1, U2>
-where
-    T: IsClose<T> + Zero,
-{
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
+    #[inline]
+    fn is_close_identity(&self) -> bool {
+        self.is_close(Self::identity())
+    }
 
     #[inline]
-    fn is_close_tol(
+    fn is_close_orthonormal(&self, tol: impl core::borrow::Borrow<T>) -> bool {
+        let rows = [
+            Vector3D::<T, U1>::new(self.m11, self.m12, self.m13),
+            Vector3D::new(self.m21, self.m22, self.m23),
+            Vector3D::new(self.m31, self.m32, self.m33),
+        ];
+        is_close_orthonormal_basis(rows, tol)
+    }
+
+    #[inline]
+    fn is_close_projective(
         &self,
         other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
@@ -369,114 +1269,644 @@ where
     ) -> bool {
         let (other, rel_tol, abs_tol): (&Self, &T, &T) =
             (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.m11.is_close_tol(&other.m11, rel_tol, abs_tol)
-            && self.m12.is_close_tol(&other.m12, rel_tol, abs_tol)
-            && self.m13.is_close_tol(&other.m13, rel_tol, abs_tol)
-            && self.m14.is_close_tol(&other.m14, rel_tol, abs_tol)
-            && self.m21.is_close_tol(&other.m21, rel_tol, abs_tol)
-            && self.m22.is_close_tol(&other.m22, rel_tol, abs_tol)
-            && self.m23.is_close_tol(&other.m23, rel_tol, abs_tol)
-            && self.m24.is_close_tol(&other.m24, rel_tol, abs_tol)
-            && self.m31.is_close_tol(&other.m31, rel_tol, abs_tol)
-            && self.m32.is_close_tol(&other.m32, rel_tol, abs_tol)
-            && self.m33.is_close_tol(&other.m33, rel_tol, abs_tol)
-            && self.m34.is_close_tol(&other.m34, rel_tol, abs_tol)
-            && self.m41.is_close_tol(&other.m41, rel_tol, abs_tol)
-            && self.m42.is_close_tol(&other.m42, rel_tol, abs_tol)
-            && self.m43.is_close_tol(&other.m43, rel_tol, abs_tol)
-            && self.m44.is_close_tol(&other.m44, rel_tol, abs_tol)
-    }
-}
-
-impl<T, U1, U2> IsClose<T> for Translation2D<T, U1, U2>
-where
-    T: IsClose<T> + Zero,
-{
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
+        let normalize = |xform: &Self| {
+            let norm = xform.squared_norm().sqrt();
+            xform.to_array().map(|x| x / norm)
+        };
+        let (lhs, rhs) = (normalize(self), normalize(other));
+        let neg_rhs = rhs.map(|x| -x);
+
+        lhs[..].is_close_tol(&rhs[..], rel_tol, abs_tol)
+            || lhs[..].is_close_tol(&neg_rhs[..], rel_tol, abs_tol)
+    }
 
     #[inline]
-    fn is_close_tol(
+    fn is_close_translation(
         &self,
         other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.x.is_close_tol(&other.x, rel_tol, abs_tol)
-            && self.y.is_close_tol(&other.y, rel_tol, abs_tol)
+        let other: &Self = other.borrow();
+        let lhs = Vector3D::<T, U2>::new(self.m41, self.m42, self.m43);
+        let rhs = Vector3D::new(other.m41, other.m42, other.m43);
+        lhs.is_close_tol(rhs, rel_tol, abs_tol)
     }
 }
 
-impl<T, U1, U2> IsClose<T> for Translation3D<T, U1, U2>
+/// Check if three vectors form an orthonormal basis, i.e. they have unit
+/// length and are mutually perpendicular, within the given absolute tolerance
+///
+/// This is useful for checking coordinate frames, e.g. the axes of a camera.
+/// The handedness of the basis is not checked.
+///
+/// ```
+/// use euclid::default::Vector3D;
+/// use isclose::euclid::is_close_orthonormal_basis;
+///
+/// let (sin, cos) = 0.5_f64.sin_cos();
+/// let basis = [
+///     Vector3D::new(cos, sin, 0.0),
+///     Vector3D::new(-sin, cos, 0.0),
+///     Vector3D::new(0.0, 0.0, 1.0),
+/// ];
+///
+/// assert!(is_close_orthonormal_basis(basis, 1e-9));
+/// ```
+#[inline]
+pub fn is_close_orthonormal_basis<T, U>(
+    basis: impl core::borrow::Borrow<[Vector3D<T, U>; 3]>,
+    tol: impl core::borrow::Borrow<T>,
+) -> bool
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T> + Zero + Float,
+{
+    let (basis, tol) = (basis.borrow(), *tol.borrow());
+    (0..3).all(|i| {
+        basis[i].square_length().is_close_abs_tol(T::one(), tol)
+            && (i + 1..3).all(|j| basis[i].dot(basis[j]).abs() <= tol)
+    })
+}
+
+/// The sum of the squares of the matrix entries
+fn entries_squared_norm<T: Float>(entries: &[T]) -> T {
+    entries.iter().fold(T::zero(), |acc, &x| acc + x * x)
+}
+
+/// The sum of the squares of the differences between pairs of matrix entries
+fn entries_squared_distance<T: Float>(lhs: &[T], rhs: &[T]) -> T {
+    lhs.iter().zip(rhs).fold(T::zero(), |acc, (&lhs, &rhs)| {
+        acc + (lhs - rhs) * (lhs - rhs)
+    })
+}
+
+/// Compares the Frobenius norms of two flattened matrices
+fn entries_is_close_frobenius<T: Float>(lhs: &[T], rhs: &[T], rel_tol: T, abs_tol: T) -> bool {
+    let distance = entries_squared_distance(lhs, rhs).sqrt();
+    let norm = Float::max(entries_squared_norm(lhs), entries_squared_norm(rhs)).sqrt();
+    distance <= norm * rel_tol + abs_tol
+}
+
+/// Implements [`FrobeniusIsClose`] for a euclid transform by comparing its
+/// flattened matrix entries
+macro_rules! impl_euclid_frobenius_is_close {
+    ($($(#[$attr:meta])* $ty:ident),+ $(,)?) => {$(
+        $(#[$attr])*
+        impl<T, U1, U2> FrobeniusIsClose<T> for $ty<T, U1, U2>
+        where
+            T: Float,
+        {
+            #[inline]
+            fn squared_norm(&self) -> T {
+                entries_squared_norm(&self.to_array())
+            }
+
+            #[inline]
+            fn squared_distance(&self, other: &Self) -> Option<T> {
+                Some(entries_squared_distance(&self.to_array(), &other.to_array()))
+            }
+
+            #[inline]
+            fn is_close_frobenius(
+                &self,
+                other: impl core::borrow::Borrow<Self>,
+                rel_tol: impl core::borrow::Borrow<T>,
+                abs_tol: impl core::borrow::Borrow<T>,
+            ) -> bool {
+                entries_is_close_frobenius(
+                    &self.to_array(),
+                    &other.borrow().to_array(),
+                    *rel_tol.borrow(),
+                    *abs_tol.borrow(),
+                )
+            }
+        }
+    )+};
+}
+
+impl_euclid_frobenius_is_close!(
+    /// Compares all six matrix entries
+    Transform2D,
+    /// Compares all sixteen matrix entries, including the projective components
+    Transform3D,
+);
+
+impl<T> HasZero for Angle<T>
+where
+    T: HasZero,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::radians(T::zero())
+    }
+}
+
+impl<T, U> HasZero for Length<T, U>
+where
+    T: HasZero,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero())
+    }
+}
+
+impl<T, U> HasZero for Size2D<T, U>
+where
+    T: HasZero,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+}
+
+impl<T, U> HasZero for Size3D<T, U>
+where
+    T: HasZero,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T, U> HasZero for Vector2D<T, U>
+where
+    T: HasZero,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+}
+
+impl<T, U> HasZero for Vector3D<T, U>
+where
+    T: HasZero,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero())
+    }
+}
 
+/// The zero matrix, which unlike [`Transform2D::identity`] is the additive
+/// identity
+impl<T, U1, U2> HasZero for Transform2D<T, U1, U2>
+where
+    T: HasZero,
+{
     #[inline]
-    fn is_close_tol(
+    fn zero() -> Self {
+        Self::new(
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+        )
+    }
+}
+
+/// The zero matrix, which unlike [`Transform3D::identity`] is the additive
+/// identity
+impl<T, U1, U2> HasZero for Transform3D<T, U1, U2>
+where
+    T: HasZero,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::new(
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+        )
+    }
+}
+
+/// An affine transform decomposed into its translation, rotation and scale
+struct Decomposed<T, U1, U2> {
+    translation: Vector3D<T, U2>,
+    rotation: Rotation3D<T, U1, U2>,
+    scale: Vector3D<T, U1>,
+}
+
+impl<T, U1, U2> Decomposed<T, U1, U2>
+where
+    T: Float,
+{
+    fn new(t: &Transform3D<T, U1, U2>) -> Self {
+        let xlate = Vector3D::new(t.m41, t.m42, t.m43);
+
+        let rows = [
+            Vector3D::<T, U1>::new(t.m11, t.m12, t.m13),
+            Vector3D::new(t.m21, t.m22, t.m23),
+            Vector3D::new(t.m31, t.m32, t.m33),
+        ];
+        // If the transform includes a reflection, negate the scale so the
+        // remaining rotation is proper
+        let sign = if rows[0].dot(rows[1].cross(rows[2])) < T::zero() {
+            -T::one()
+        } else {
+            T::one()
+        };
+        let scale = Vector3D::new(
+            rows[0].length() * sign,
+            rows[1].length() * sign,
+            rows[2].length() * sign,
+        );
+        let [r1, r2, r3] = [rows[0] / scale.x, rows[1] / scale.y, rows[2] / scale.z];
+
+        // Convert the rotation matrix to a quaternion, choosing the numerically
+        // stablest formula based on the diagonal
+        let (one, two, four) = (
+            T::one(),
+            T::one() + T::one(),
+            T::from(4).unwrap_or_else(T::nan),
+        );
+        let trace = r1.x + r2.y + r3.z;
+        let rot = if trace > T::zero() {
+            let s = (one + trace).sqrt() * two;
+            Rotation3D::quaternion(
+                (r2.z - r3.y) / s,
+                (r3.x - r1.z) / s,
+                (r1.y - r2.x) / s,
+                s / four,
+            )
+        } else if r1.x > r2.y && r1.x > r3.z {
+            let s = (one + r1.x - r2.y - r3.z).sqrt() * two;
+            Rotation3D::quaternion(
+                s / four,
+                (r1.y + r2.x) / s,
+                (r3.x + r1.z) / s,
+                (r2.z - r3.y) / s,
+            )
+        } else if r2.y > r3.z {
+            let s = (one + r2.y - r1.x - r3.z).sqrt() * two;
+            Rotation3D::quaternion(
+                (r1.y + r2.x) / s,
+                s / four,
+                (r2.z + r3.y) / s,
+                (r3.x - r1.z) / s,
+            )
+        } else {
+            let s = (one + r3.z - r1.x - r2.y).sqrt() * two;
+            Rotation3D::quaternion(
+                (r3.x + r1.z) / s,
+                (r2.z + r3.y) / s,
+                s / four,
+                (r1.y - r2.x) / s,
+            )
+        };
+
+        Self {
+            translation: xlate,
+            rotation: rot,
+            scale,
+        }
+    }
+}
+
+/// Additional comparisons for [`Vector2D`]
+pub trait Vector2DIsClose<T> {
+    /// Check if two vectors point in approximately the same direction using
+    /// the given relative and absolute tolerances, ignoring their magnitudes
+    ///
+    /// Both vectors are normalized before comparing. If either vector has zero
+    /// length it has no direction, so the vectors are compared directly.
+    fn is_close_direction(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two vectors are approximately parallel, ignoring their
+    /// magnitudes and signs
+    ///
+    /// The vectors are parallel if the magnitude of their cross product is
+    /// within the given tolerance of the product of their lengths, i.e. if the
+    /// sine of the angle between them is within the tolerance. A zero length
+    /// vector is parallel to every vector.
+    fn is_close_parallel(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two vectors are approximately perpendicular
+    ///
+    /// The vectors are perpendicular if the magnitude of their dot product is
+    /// within the given tolerance of the product of their lengths, i.e. if the
+    /// cosine of the angle between them is within the tolerance. A zero length
+    /// vector is perpendicular to every vector.
+    fn is_close_perpendicular(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two vectors have approximately equal magnitudes using the
+    /// given relative tolerance, and if the angle between them is within the
+    /// given absolute tolerance in radians
+    fn is_close_polar(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        mag_tol: impl core::borrow::Borrow<T>,
+        angle_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if the scalar projection of the vector onto the given direction
+    /// is within the given absolute tolerance of the expected value
+    ///
+    /// The scalar projection is the signed length of the component of the
+    /// vector along the direction, e.g. a velocity's speed along an axis. The
+    /// length of `onto` doesn't matter, but a zero length vector has no
+    /// direction, so nothing is close when projected onto it.
+    fn is_close_projection(
+        &self,
+        onto: impl core::borrow::Borrow<Self>,
+        expected: impl core::borrow::Borrow<T>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
+
+impl<T, U> Vector2DIsClose<T> for Vector2D<T, U>
+where
+    T: IsClose<T> + Zero + Float,
+{
+    #[inline]
+    fn is_close_direction(
         &self,
         other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.x.is_close_tol(&other.x, rel_tol, abs_tol)
-            && self.y.is_close_tol(&other.y, rel_tol, abs_tol)
-            && self.z.is_close_tol(&other.z, rel_tol, abs_tol)
+        let other: &Self = other.borrow();
+        if self.length() == T::zero() || other.length() == T::zero() {
+            return self.is_close_tol(other, rel_tol, abs_tol);
+        }
+        self.normalize()
+            .is_close_tol(other.normalize(), rel_tol, abs_tol)
+    }
+
+    #[inline]
+    fn is_close_parallel(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let other: &Self = other.borrow();
+        self.cross(*other).abs() <= self.length() * other.length() * *tol.borrow()
+    }
+
+    #[inline]
+    fn is_close_perpendicular(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let other: &Self = other.borrow();
+        self.dot(*other).abs() <= self.length() * other.length() * *tol.borrow()
+    }
+
+    #[inline]
+    fn is_close_polar(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        mag_tol: impl core::borrow::Borrow<T>,
+        angle_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let other: &Self = other.borrow();
+        let angle = self.cross(*other).abs().atan2(self.dot(*other));
+        self.length().is_close_rel_tol(other.length(), mag_tol) && angle <= *angle_tol.borrow()
+    }
+
+    #[inline]
+    fn is_close_projection(
+        &self,
+        onto: impl core::borrow::Borrow<Self>,
+        expected: impl core::borrow::Borrow<T>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let onto: &Self = onto.borrow();
+        let projection = self.dot(*onto) / onto.length();
+        projection.is_close_abs_tol(expected, tol)
     }
 }
 
-impl<T, U> IsClose<T> for Vector2D<T, U>
+/// Additional comparisons for [`Vector3D`]
+pub trait Vector3DIsClose<T> {
+    /// Check if two vectors point in approximately the same direction using
+    /// the given relative and absolute tolerances, ignoring their magnitudes
+    ///
+    /// Both vectors are normalized before comparing. If either vector has zero
+    /// length it has no direction, so the vectors are compared directly.
+    fn is_close_direction(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two vectors are approximately parallel, ignoring their
+    /// magnitudes and signs
+    ///
+    /// The vectors are parallel if the magnitude of their cross product is
+    /// within the given tolerance of the product of their lengths, i.e. if the
+    /// sine of the angle between them is within the tolerance. A zero length
+    /// vector is parallel to every vector.
+    fn is_close_parallel(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two vectors are approximately perpendicular
+    ///
+    /// The vectors are perpendicular if the magnitude of their dot product is
+    /// within the given tolerance of the product of their lengths, i.e. if the
+    /// cosine of the angle between them is within the tolerance. A zero length
+    /// vector is perpendicular to every vector.
+    fn is_close_perpendicular(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two vectors have approximately equal magnitudes using the
+    /// given relative tolerance, and if the angle between them is within the
+    /// given absolute tolerance in radians
+    fn is_close_polar(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        mag_tol: impl core::borrow::Borrow<T>,
+        angle_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two vectors are approximately equal in spherical coordinates,
+    /// using separate absolute tolerances for the radius, the polar angle
+    /// `θ` from the positive z axis, and the azimuthal angle `φ` around it
+    ///
+    /// Both angles are in radians, and the azimuths are compared across the
+    /// wraparound at `±π`. The azimuth is undefined for vectors on the z
+    /// axis, so it is ignored if either vector lies on it.
+    fn is_close_spherical(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        r_tol: impl core::borrow::Borrow<T>,
+        theta_tol: impl core::borrow::Borrow<T>,
+        phi_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if two vectors are approximately equal using the given relative
+    /// and absolute tolerances, scaling the relative tolerance by the largest
+    /// magnitude of any component of either vector
+    ///
+    /// The default comparison scales the tolerance of each component by the
+    /// magnitudes of that component alone, so small components of a vector
+    /// dominated by another component are compared much more strictly. This
+    /// treats all components uniformly instead.
+    fn is_close_vecrel(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+
+    /// Check if the vector is approximately equal to the cross product of `a`
+    /// and `b` using the given relative and absolute tolerances
+    ///
+    /// The cross product follows the right-hand rule, so swapping `a` and `b`
+    /// negates the expected vector.
+    fn is_close_cross(
+        &self,
+        a: impl core::borrow::Borrow<Self>,
+        b: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
+
+impl<T, U> Vector3DIsClose<T> for Vector3D<T, U>
 where
-    T: IsClose<T> + Zero,
+    T: IsClose<T> + Zero + Float,
 {
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
+    #[inline]
+    fn is_close_direction(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let other: &Self = other.borrow();
+        if self.length() == T::zero() || other.length() == T::zero() {
+            return self.is_close_tol(other, rel_tol, abs_tol);
+        }
+        self.normalize()
+            .is_close_tol(other.normalize(), rel_tol, abs_tol)
+    }
+
+    #[inline]
+    fn is_close_parallel(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let other: &Self = other.borrow();
+        self.cross(*other).length() <= self.length() * other.length() * *tol.borrow()
+    }
+
+    #[inline]
+    fn is_close_perpendicular(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let other: &Self = other.borrow();
+        self.dot(*other).abs() <= self.length() * other.length() * *tol.borrow()
+    }
+
+    #[inline]
+    fn is_close_polar(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        mag_tol: impl core::borrow::Borrow<T>,
+        angle_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let other: &Self = other.borrow();
+        let angle = self.cross(*other).length().atan2(self.dot(*other));
+        self.length().is_close_rel_tol(other.length(), mag_tol) && angle <= *angle_tol.borrow()
+    }
+
+    #[inline]
+    fn is_close_spherical(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        r_tol: impl core::borrow::Borrow<T>,
+        theta_tol: impl core::borrow::Borrow<T>,
+        phi_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let other: &Self = other.borrow();
+        let (lhs_rho, rhs_rho) = (self.x.hypot(self.y), other.x.hypot(other.y));
+        let (lhs_theta, rhs_theta) = (lhs_rho.atan2(self.z), rhs_rho.atan2(other.z));
+
+        let phi_close = lhs_rho == T::zero() || rhs_rho == T::zero() || {
+            let diff = self.y.atan2(self.x) - other.y.atan2(other.x);
+            diff.sin().atan2(diff.cos()).abs() <= *phi_tol.borrow()
+        };
+
+        self.length().is_close_abs_tol(other.length(), r_tol)
+            && (lhs_theta - rhs_theta).abs() <= *theta_tol.borrow()
+            && phi_close
+    }
 
     #[inline]
-    fn is_close_tol(
+    fn is_close_vecrel(
         &self,
         other: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.x.is_close_tol(&other.x, rel_tol, abs_tol)
-            && self.y.is_close_tol(&other.y, rel_tol, abs_tol)
+        let other: &Self = other.borrow();
+        let scale = [self.x, self.y, self.z, other.x, other.y, other.z]
+            .iter()
+            .fold(T::zero(), |max, x| Float::max(max, Float::abs(*x)));
+        let tol = scale * *rel_tol.borrow() + *abs_tol.borrow();
+        self.is_close_abs_tol(other, tol)
     }
-}
-
-impl<T, U> IsClose<T> for Vector3D<T, U>
-where
-    T: IsClose<T> + Zero,
-{
-    const ABS_TOL: T = T::ABS_TOL;
-    const REL_TOL: T = T::REL_TOL;
 
     #[inline]
-    fn is_close_tol(
+    fn is_close_cross(
         &self,
-        other: impl core::borrow::Borrow<Self>,
+        a: impl core::borrow::Borrow<Self>,
+        b: impl core::borrow::Borrow<Self>,
         rel_tol: impl core::borrow::Borrow<T>,
         abs_tol: impl core::borrow::Borrow<T>,
     ) -> bool {
-        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
-            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        self.x.is_close_tol(&other.x, rel_tol, abs_tol)
-            && self.y.is_close_tol(&other.y, rel_tol, abs_tol)
-            && self.z.is_close_tol(&other.z, rel_tol, abs_tol)
+        let (a, b): (&Self, &Self) = (a.borrow(), b.borrow());
+        self.is_close_tol(a.cross(*b), rel_tol, abs_tol)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use core::f64::consts::{FRAC_PI_3, PI};
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI, TAU};
 
     use euclid::default::{
         Box2D, Box3D, HomogeneousVector, Length, Point2D, Point3D, Rect, RigidTransform3D,
@@ -485,7 +1915,57 @@ mod tests {
     };
     use euclid::Angle;
 
-    use crate::assert_is_close;
+    use crate::{assert_is_close, IsClose};
+
+    /// Checks that every field is compared using the given tolerances, and
+    /// that the default tolerances are forwarded from [`f64`]
+    fn check_fields<V, const N: usize>(new: impl Fn([f64; N]) -> V)
+    where
+        V: IsClose<f64>,
+    {
+        let lhs = [1.0; N];
+        for i in 0..N {
+            let mut rhs = lhs;
+            rhs[i] += 0.5;
+
+            assert!(new(lhs).is_close(new(lhs)));
+            assert!(!new(lhs).is_close(new(rhs)), "field {}", i);
+            assert!(new(lhs).is_close_abs_tol(new(rhs), 0.5), "field {}", i);
+            assert!(!new(lhs).is_close_abs_tol(new(rhs), 0.4), "field {}", i);
+            assert!(new(lhs).is_close_rel_tol(new(rhs), 0.4), "field {}", i);
+            assert!(!new(lhs).is_close_rel_tol(new(rhs), 0.3), "field {}", i);
+        }
+
+        assert_eq!(V::ABS_TOL.to_bits(), f64::ABS_TOL.to_bits());
+        assert_eq!(V::REL_TOL.to_bits(), f64::REL_TOL.to_bits());
+    }
+
+    #[test]
+    fn is_close_fields() {
+        check_fields(|[a]| Angle::radians(a));
+        check_fields(|[a, b, c, d]| Box2D::new(Point2D::new(a, b), Point2D::new(c, d)));
+        check_fields(|[a, b, c, d, e, f]| Box3D::new(Point3D::new(a, b, c), Point3D::new(d, e, f)));
+        check_fields(|[a, b, c, d]| HomogeneousVector::new(a, b, c, d));
+        check_fields(|[a]| Length::new(a));
+        check_fields(|[a, b]| Point2D::new(a, b));
+        check_fields(|[a, b, c]| Point3D::new(a, b, c));
+        check_fields(|[a, b, c, d]| Rect::new(Point2D::new(a, b), Size2D::new(c, d)));
+        check_fields(|[a, b, c, d, e, f, g]| {
+            RigidTransform3D::new(Rotation3D::quaternion(a, b, c, d), Vector3D::new(e, f, g))
+        });
+        check_fields(|[a]| Rotation2D::radians(a));
+        check_fields(|[a, b, c, d]| Rotation3D::quaternion(a, b, c, d));
+        check_fields(|[a]| Scale::new(a));
+        check_fields(|[a, b, c, d]| SideOffsets2D::new(a, b, c, d));
+        check_fields(|[a, b]| Size2D::new(a, b));
+        check_fields(|[a, b, c]| Size3D::new(a, b, c));
+        check_fields(Transform2D::from_array);
+        check_fields(Transform3D::from_array);
+        check_fields(|[a, b]| Translation2D::new(a, b));
+        check_fields(|[a, b, c]| Translation3D::new(a, b, c));
+        check_fields(|[a, b]| Vector2D::new(a, b));
+        check_fields(|[a, b, c]| Vector3D::new(a, b, c));
+    }
 
     #[test]
     fn angle() {
@@ -495,6 +1975,42 @@ mod tests {
         assert_is_close!(angle1, angle2);
     }
 
+    #[test]
+    fn angle_degrees() {
+        use super::AngleIsClose as _;
+        use crate::IsClose as _;
+
+        let angle1 = Angle::degrees(45.0);
+        let angle2 = Angle::degrees(45.3);
+
+        assert!(angle1.is_close_degrees(angle2, 0.5));
+        assert!(!angle1.is_close_abs_tol(angle2, 1e-3));
+        assert!(!angle1.is_close_degrees(angle2, 0.1));
+        assert!(Angle::radians(PI).is_close_degrees(Angle::degrees(180.0), 1e-9));
+    }
+
+    #[test]
+    fn angle_trig() {
+        use super::AngleIsClose as _;
+        use crate::IsClose as _;
+
+        // Across the wraparound boundary
+        let angle1 = Angle::radians(0.0);
+        let angle2 = Angle::radians(TAU - 1e-6);
+        assert!(angle1.is_close_trig(angle2, 1e-5));
+        assert!(!angle1.is_close_abs_tol(angle2, 1e-5));
+        assert!(angle1.is_close_trig(Angle::radians(TAU), 1e-9));
+
+        // Either side of π
+        let angle3 = Angle::radians(PI - 1e-6);
+        let angle4 = Angle::radians(-PI + 1e-6);
+        assert!(angle3.is_close_trig(angle4, 1e-5));
+        assert!(!angle3.is_close_abs_tol(angle4, 1e-5));
+
+        assert!(!angle1.is_close_trig(Angle::radians(1e-3), 1e-5));
+        assert!(!angle1.is_close_trig(Angle::radians(PI), 1e-5));
+    }
+
     #[test]
     fn box_2d() {
         let box1 = Box2D::new(Point2D::new(1.0, 2.0), Point2D::new(3.0, 4.0));
@@ -513,6 +2029,102 @@ mod tests {
         assert_is_close!(box1, box2);
     }
 
+    #[test]
+    fn box_2d_by_extent() {
+        use super::Box2DIsClose as _;
+        use crate::IsClose as _;
+
+        let large1 = Box2D::new(Point2D::new(0.0, 0.0), Point2D::new(1000.0, 1000.0));
+        let large2 = Box2D::new(Point2D::new(0.5, -0.5), Point2D::new(1000.5, 999.5));
+        let small1 = Box2D::new(Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0));
+        let small2 = Box2D::new(Point2D::new(0.5, -0.5), Point2D::new(1.5, 0.5));
+
+        assert!(large1.is_close_by_extent(large2, 1e-3, 0.0));
+        assert!(!large1.is_close_rel_tol(large2, 1e-3));
+        assert!(!small1.is_close_by_extent(small2, 1e-3, 0.0));
+        assert!(small1.is_close_by_extent(small2, 1.0, 0.0));
+    }
+
+    #[test]
+    fn box_2d_contains() {
+        use super::Box2DIsClose as _;
+
+        let bounds = Box2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 5.0));
+
+        // Just inside, and on the edges
+        assert!(bounds.is_close_contains(Point2D::new(9.99, 0.01), 0.0));
+        assert!(bounds.is_close_contains(Point2D::new(10.0, 5.0), 0.0));
+
+        // Just outside, within tolerance
+        assert!(bounds.is_close_contains(Point2D::new(10.05, 2.0), 0.1));
+        assert!(bounds.is_close_contains(Point2D::new(-0.05, -0.05), 0.1));
+        assert!(!bounds.is_close_contains(Point2D::new(10.05, 2.0), 0.0));
+
+        // Far outside
+        assert!(!bounds.is_close_contains(Point2D::new(15.0, 2.0), 0.1));
+        assert!(!bounds.is_close_contains(Point2D::new(5.0, -1.0), 0.1));
+    }
+
+    #[test]
+    fn box_3d_by_extent() {
+        use super::Box3DIsClose as _;
+        use crate::IsClose as _;
+
+        let large1 = Box3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::splat(1000.0));
+        let large2 = Box3D::new(Point3D::splat(0.5), Point3D::splat(1000.5));
+        let small1 = Box3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::splat(1.0));
+        let small2 = Box3D::new(Point3D::splat(0.5), Point3D::splat(1.5));
+
+        assert!(large1.is_close_by_extent(large2, 1e-3, 0.0));
+        assert!(!large1.is_close_rel_tol(large2, 1e-3));
+        assert!(!small1.is_close_by_extent(small2, 1e-3, 0.0));
+        assert!(small1.is_close_by_extent(small2, 1.0, 0.0));
+    }
+
+    #[test]
+    fn box_3d_transformed() {
+        use super::Box3DIsClose as _;
+
+        let bbox = Box3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 2.0, 3.0));
+        let xform = Transform3D::rotation(0.0, 0.0, 1.0, Angle::radians(FRAC_PI_2))
+            .then_scale(2.0, 2.0, 2.0)
+            .then_translate(Vector3D::new(10.0, 0.0, 0.0));
+        let expected = Box3D::new(Point3D::new(6.0, 0.0, 0.0), Point3D::new(10.0, 2.0, 6.0));
+
+        assert!(bbox.is_close_transformed(expected, xform, 1e-9, 1e-9));
+        assert!(bbox.is_close_transformed(bbox, Transform3D::identity(), 0.0, 0.0));
+        assert!(!bbox.is_close_transformed(bbox, xform, 1e-9, 1e-9));
+        assert!(!bbox.is_close_transformed(
+            expected.translate(Vector3D::new(0.0, 0.0, 0.1)),
+            xform,
+            1e-9,
+            1e-9
+        ));
+
+        // Rotations by other angles don't give an axis aligned box
+        let tilted = Transform3D::rotation(0.0, 0.0, 1.0, Angle::radians(FRAC_PI_3));
+        let bounds = tilted.outer_transformed_box3d(&bbox).unwrap();
+        assert!(!bbox.is_close_transformed(bounds, tilted, 1e-9, 1e-9));
+    }
+
+    #[test]
+    fn box_3d_volume() {
+        use super::Box3DIsClose as _;
+
+        let cube = Box3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::splat(2.0));
+        let moved = Box3D::new(Point3D::splat(10.0), Point3D::splat(12.0));
+        let flat = Box3D::new(
+            Point3D::new(-1.0, 0.0, 0.0),
+            Point3D::new(7.0, 1.0, 1.0 + 1e-9),
+        );
+        let larger = Box3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(2.0, 2.0, 2.1));
+
+        assert!(cube.is_close_volume(moved, 1e-9));
+        assert!(cube.is_close_volume(flat, 1e-6));
+        assert!(!cube.is_close_volume(larger, 1e-3));
+        assert!(cube.is_close_volume(larger, 0.1));
+    }
+
     #[test]
     fn homogen_vec() {
         let vec1 = HomogeneousVector::new(1.0, 2.0, 3.0, 1.0);
@@ -529,6 +2141,56 @@ mod tests {
         assert_is_close!(length1, length2);
     }
 
+    #[test]
+    fn length_converted() {
+        use super::LengthIsClose as _;
+
+        enum Meters {}
+        enum Millimeters {}
+
+        let length1 = euclid::Length::<_, Meters>::new(1.0);
+        let length2 = euclid::Length::<_, Millimeters>::new(1000.0);
+        let scale = euclid::Scale::new(1e-3);
+
+        assert!(length1.is_close_converted(length2, scale, 1e-9, 0.0));
+        assert!(!length1.is_close_converted(length2 * 1.001, scale, 1e-9, 0.0));
+    }
+
+    #[test]
+    fn length_unit() {
+        use super::{LengthIsClose as _, UnitTolerance};
+        use crate::IsClose as _;
+
+        enum Millimeters {}
+
+        impl UnitTolerance<f64> for Millimeters {
+            const ABS_TOL: f64 = 1e-3;
+            const REL_TOL: f64 = 1e-6;
+        }
+
+        let length1 = euclid::Length::<_, Millimeters>::new(0.0);
+        let length2 = euclid::Length::<_, Millimeters>::new(1e-4);
+
+        assert!(length1.is_close_unit(length2));
+        assert!(!length1.is_close(length2));
+        assert!(!length1.is_close_unit(length2 * 100.0));
+        assert!(euclid::Length::<_, Millimeters>::new(1e6).is_close_unit(length2 * 1e10));
+    }
+
+    #[test]
+    fn length_sum() {
+        use super::LengthIsClose as _;
+
+        let segments = [0.1, 0.2, 0.3, 0.4, 1.7].map(Length::new);
+        let total = Length::new(2.7);
+
+        assert!(total.is_close_sum(&segments, 1e-9, 0.0));
+        assert!(!total.is_close_sum(&segments[1..], 1e-9, 0.0));
+        assert!(!(total * 1.001).is_close_sum(&segments, 1e-9, 0.0));
+        assert!((total * 1.001).is_close_sum(&segments, 1e-2, 0.0));
+        assert!(Length::new(0.0).is_close_sum(&[], 0.0, 0.0));
+    }
+
     #[test]
     fn point_2d() {
         let point1 = Point2D::new(2.5, 3.0);
@@ -537,6 +2199,113 @@ mod tests {
         assert_is_close!(point1, point2);
     }
 
+    #[test]
+    fn point_2d_in_box() {
+        use super::Point2DIsClose as _;
+
+        let small = Box2D::new(Point2D::new(0.0, 0.0), Point2D::new(3.0, 4.0));
+        let large = small.scale(1e3, 1e3);
+
+        let point1 = Point2D::new(1e3, 1e3);
+        let point2 = Point2D::new(1e3 + 1e-2, 1e3 - 1e-2);
+
+        assert!(point1.is_close_in_box(point2, large, 1e-5, 0.0));
+        assert!(!point1.is_close_in_box(point2, small, 1e-5, 0.0));
+        assert!(point1.is_close_in_box(point2, small, 1e-5, 1e-2));
+    }
+
+    #[test]
+    fn point_2d_manhattan() {
+        use super::Point2DIsClose as _;
+        use crate::IsClose as _;
+
+        let point1 = Point2D::new(1.0, 2.0);
+        let point2 = Point2D::new(1.3, 1.7);
+
+        assert!(point1.is_close_abs_tol(point2, 0.5));
+        assert!(!point1.is_close_manhattan(point2, 0.5));
+        assert!(point1.is_close_manhattan(point2, 0.7));
+        assert!(point1.is_close_manhattan(Point2D::new(1.6, 2.0), 0.7));
+        assert!(!point1.is_close_abs_tol(Point2D::new(1.6, 2.0), 0.5));
+    }
+
+    #[test]
+    fn point_2d_snapped() {
+        use super::Point2DIsClose as _;
+
+        let point1 = Point2D::new(10.2, 19.9);
+
+        // Same grid cell
+        assert!(point1.is_close_snapped(Point2D::new(9.6, 20.4), 1.0));
+        assert!(point1.is_close_snapped(Point2D::new(11.0, 21.0), 4.0));
+
+        // Either side of a cell boundary
+        assert!(!Point2D::new(0.49, 0.0).is_close_snapped(Point2D::new(0.51, 0.0), 1.0));
+        assert!(!point1.is_close_snapped(Point2D::new(10.2, 20.6), 1.0));
+
+        assert!(point1.is_close_snapped_tol(Point2D::new(9.9, 20.0), 1.0, 0.25));
+        assert!(!point1.is_close_snapped_tol(Point2D::new(9.6, 20.0), 1.0, 0.25));
+        assert!(point1.is_close_snapped_tol(Point2D::new(9.6, 20.0), 1.0, 0.5));
+        assert!(Point2D::new(0.4, 0.0).is_close_snapped_tol(Point2D::new(0.4, 0.0), 1.0, 0.25));
+    }
+
+    #[test]
+    fn point_2d_on_line() {
+        use super::Point2DIsClose as _;
+
+        let (p0, p1) = (Point2D::new(1.0, 1.0), Point2D::new(3.0, 3.0));
+
+        assert!(Point2D::new(2.0, 2.0).is_close_on_line(p0, p1, 1e-9));
+        assert!(Point2D::new(0.1 + 0.2, 0.3).is_close_on_line(p0, p1, 1e-9));
+
+        // Beyond the ends of the segment
+        assert!(Point2D::new(-5.0, -5.0).is_close_on_line(p0, p1, 1e-9));
+        assert!(Point2D::new(10.0, 10.0).is_close_on_line(p1, p0, 1e-9));
+
+        // Perpendicular distance of 0.1 / sqrt(2)
+        let off = Point2D::new(2.0, 2.1);
+        assert!(!off.is_close_on_line(p0, p1, 0.07));
+        assert!(off.is_close_on_line(p0, p1, 0.071));
+
+        // Degenerate line
+        assert!(Point2D::new(1.0, 1.05).is_close_on_line(p0, p0, 0.1));
+        assert!(!Point2D::new(2.0, 2.0).is_close_on_line(p0, p0, 0.1));
+    }
+
+    #[test]
+    fn point_2d_polygon() {
+        use super::polygon_is_close;
+
+        let polygon = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, 0.0),
+            Point2D::new(4.0, 3.0),
+            Point2D::new(1.0, 2.0),
+        ];
+        let rotated = [
+            Point2D::new(4.0, 3.0),
+            Point2D::new(1.0 + 1e-12, 2.0),
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, 0.0),
+        ];
+        let reversed = [polygon[1], polygon[0], polygon[3], polygon[2]];
+        let shuffled = [polygon[0], polygon[2], polygon[1], polygon[3]];
+
+        assert!(polygon_is_close(&polygon, &polygon, 0.0, 0.0));
+        assert!(polygon_is_close(&polygon, &rotated, 1e-9, 0.0));
+        assert!(!polygon_is_close(&polygon, &rotated, 0.0, 0.0));
+        assert!(polygon_is_close(&polygon, &reversed, 0.0, 0.0));
+        assert!(polygon_is_close(&reversed, &rotated, 1e-9, 0.0));
+        assert!(!polygon_is_close(&polygon, &shuffled, 1e-9, 0.0));
+        assert!(!polygon_is_close(&polygon, &polygon[..3], 1.0, 1.0));
+        assert!(polygon_is_close::<f64, euclid::UnknownUnit>(
+            &[],
+            &[],
+            0.0,
+            0.0
+        ));
+    }
+
     #[test]
     fn point_3d() {
         let point1 = Point3D::new(2.5, 3.0, 4.5);
@@ -545,6 +2314,56 @@ mod tests {
         assert_is_close!(point1, point2);
     }
 
+    #[test]
+    fn point_3d_on_plane() {
+        use super::Point3DIsClose as _;
+
+        let normal = Vector3D::new(0.0, 0.0, 1.0);
+        let point1 = Point3D::new(0.1 + 0.2, 1.0, 5.0);
+        let point2 = Point3D::new(0.3, 1.0, -2.0);
+        let point3 = Point3D::new(0.3, 1.1, 5.0);
+
+        assert!(point1.is_close_on_plane(point2, normal, 1e-9, 1e-9));
+        assert!(point1.is_close_on_plane(point2, normal * 2.0, 1e-9, 1e-9));
+        assert!(!point1.is_close_on_plane(point3, normal, 1e-9, 1e-9));
+        assert!(!point1.is_close_on_plane(point2, Vector3D::new(1.0, 0.0, 0.0), 1e-9, 1e-9));
+
+        // Without a plane the points are compared directly
+        let zero = Vector3D::zero();
+        assert!(!point1.is_close_on_plane(point2, zero, 1e-9, 1e-9));
+        assert!(point1.is_close_on_plane(Point3D::new(0.3, 1.0, 5.0), zero, 1e-9, 1e-9));
+    }
+
+    #[test]
+    fn point_3d_barycentric() {
+        use super::Point3DIsClose as _;
+
+        let tri = [
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(100.0, 0.0, 0.0),
+            Point3D::new(0.0, 100.0, 0.0),
+        ];
+        let point1 = Point3D::new(25.0, 25.0, 0.0);
+        let point2 = Point3D::new(25.05, 24.95, 0.0);
+        let point3 = Point3D::new(30.0, 25.0, 0.0);
+
+        assert!(point1.is_close_barycentric(point2, tri, 1e-3));
+        assert!(!point1.is_close_barycentric(point2, tri, 1e-4));
+        assert!(!point1.is_close_barycentric(point3, tri, 1e-3));
+
+        // Scaling the mesh scales the absolute difference, but not the
+        // difference in barycentric coordinates
+        let scaled = tri.map(|p| p * 1e-3);
+        assert!((point1 * 1e-3).is_close_barycentric(point2 * 1e-3, scaled, 1e-3));
+
+        // Offsets along the triangle's normal are ignored
+        let point4 = Point3D::new(25.0, 25.0, 10.0);
+        assert!(point1.is_close_barycentric(point4, tri, 1e-9));
+
+        let degenerate = [tri[0], tri[1], Point3D::new(50.0, 0.0, 0.0)];
+        assert!(!point1.is_close_barycentric(point1, degenerate, 1.0));
+    }
+
     #[test]
     fn rect() {
         let rect1 = Rect::new(Point2D::new(1.0, 2.0), Size2D::new(1.0, 1.0));
@@ -555,6 +2374,24 @@ mod tests {
         assert_is_close!(rect1, rect2);
     }
 
+    #[test]
+    fn rect_iou() {
+        use super::RectIsClose as _;
+
+        let rect1 = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(2.0, 2.0));
+        let rect2 = Rect::new(Point2D::new(1.0, 0.0), Size2D::new(2.0, 2.0));
+        let rect3 = Rect::new(Point2D::new(5.0, 5.0), Size2D::new(2.0, 2.0));
+        let empty = Rect::new(Point2D::new(0.0, 0.0), Size2D::zero());
+
+        // Intersection 2, union 6
+        assert!(rect1.is_close_iou(rect2, 0.3));
+        assert!(!rect1.is_close_iou(rect2, 0.5));
+        assert!(rect1.is_close_iou(rect1, 1.0));
+        assert!(!rect1.is_close_iou(rect3, 1e-9));
+        assert!(rect1.is_close_iou(rect3, 0.0));
+        assert!(!empty.is_close_iou(empty, 0.0));
+    }
+
     #[test]
     fn rigid_transform_3d() {
         let rt1 = RigidTransform3D::new(
@@ -580,6 +2417,41 @@ mod tests {
         assert!(rot1.is_close(rot2));
     }
 
+    #[test]
+    fn rotation_2d_wrapped() {
+        use super::Rotation2DIsClose as _;
+        use crate::IsClose as _;
+
+        let rot1 = Rotation2D::new(Angle::radians(0.0));
+        let rot2 = Rotation2D::new(Angle::radians(2.0 * PI));
+        let rot3 = Rotation2D::new(Angle::radians(1e-3) - Angle::two_pi());
+
+        assert!(!rot1.is_close(rot2));
+        assert!(rot1.is_close_wrapped(rot2, 1e-9, 1e-9));
+        assert!(rot2.is_close_wrapped(rot1, 1e-9, 1e-9));
+        assert!(rot1.is_close_wrapped(rot3, 0.0, 1e-2));
+        assert!(!rot1.is_close_wrapped(rot3, 0.0, 1e-4));
+    }
+
+    #[test]
+    fn rotation_2d_on_vector() {
+        use super::Rotation2DIsClose as _;
+
+        let vector = Vector2D::new(3.0, 4.0);
+        let rot1 = Rotation2D::new(Angle::radians(0.0));
+        let rot2 = Rotation2D::new(Angle::radians(2.0 * PI));
+        let rot3 = Rotation2D::new(Angle::radians(1e-3) - Angle::two_pi() - Angle::two_pi());
+
+        assert!(rot1.is_close_on_vector(rot2, vector, 1e-9, 1e-9));
+        assert!(rot2.is_close_on_vector(rot1, vector, 1e-9, 1e-9));
+        assert!(rot1.is_close_on_vector(rot3, vector, 0.0, 1e-2));
+        assert!(!rot1.is_close_on_vector(rot3, vector, 0.0, 1e-3));
+
+        // The tolerance scales with the length of the vector
+        assert!(rot1.is_close_on_vector(rot3, vector * 1e-3, 0.0, 1e-5));
+        assert!(rot1.is_close_on_vector(rot3, Vector2D::zero(), 0.0, 0.0));
+    }
+
     #[test]
     fn rotation_3d() {
         let rot1 = Rotation3D::around_axis(Vector3D::new(1.0, 2.0, 3.0), Angle::degrees(90.0));
@@ -588,6 +2460,93 @@ mod tests {
         assert_is_close!(rot1, rot2);
     }
 
+    #[test]
+    fn rotation_3d_angle() {
+        use super::Rotation3DIsClose as _;
+
+        let axis = Vector3D::new(1.0, 2.0, 3.0);
+        let rot1 = Rotation3D::around_axis(axis, Angle::radians(FRAC_PI_3));
+        let rot2 = Rotation3D::around_axis(axis, Angle::radians(FRAC_PI_3 + 1e-6));
+        let rot3 = Rotation3D::quaternion(-rot1.i, -rot1.j, -rot1.k, -rot1.r);
+        let rot4 = Rotation3D::around_axis(axis, Angle::radians(FRAC_PI_3 + 1e-2));
+
+        assert!(rot1.is_close_angle(rot2, 1e-5));
+        assert!(!rot1.is_close_angle(rot2, 1e-7));
+        assert!(rot1.is_close_angle(rot3, 1e-9));
+        assert!(!rot1.is_close_angle(rot4, 1e-3));
+    }
+
+    #[test]
+    fn rotation_3d_orthonormal() {
+        use super::Rotation3DIsClose as _;
+
+        let rot = Rotation3D::around_axis(Vector3D::new(1.0, 2.0, 3.0), Angle::radians(FRAC_PI_3));
+        let scaled = Rotation3D::quaternion(rot.i * 1.1, rot.j * 1.1, rot.k * 1.1, rot.r * 1.1);
+
+        assert!(rot.is_close_orthonormal(1e-9));
+        assert!(Rotation3D::<f64>::identity().is_close_orthonormal(1e-9));
+        assert!(!scaled.is_close_orthonormal(1e-3));
+    }
+
+    /// Composes a rotation from extrinsic Euler angles about the given axes
+    fn euler(axes: [usize; 3], angles: [f64; 3]) -> Rotation3D<f64> {
+        axes.iter()
+            .zip(angles)
+            .fold(Rotation3D::identity(), |rot, (&axis, angle)| {
+                let angle = Angle::radians(angle);
+                rot.then(&match axis {
+                    0 => Rotation3D::around_x(angle),
+                    1 => Rotation3D::around_y(angle),
+                    _ => Rotation3D::around_z(angle),
+                })
+            })
+    }
+
+    #[test]
+    fn rotation_3d_euler_angles() {
+        use super::{euler_angles, EulerOrder};
+
+        let orders = [
+            EulerOrder::Xyz,
+            EulerOrder::Xzy,
+            EulerOrder::Yxz,
+            EulerOrder::Yzx,
+            EulerOrder::Zxy,
+            EulerOrder::Zyx,
+        ];
+        let angles = [0.3, -0.4, 2.5];
+
+        for order in orders {
+            let (decomposed, _) = euler_angles(&euler(order.axes().0, angles), order);
+            assert_is_close!(decomposed[..], angles[..]);
+        }
+    }
+
+    #[test]
+    fn rotation_3d_euler() {
+        use super::{EulerOrder, Rotation3DIsClose as _};
+
+        let axes = EulerOrder::Zyx.axes().0;
+        let rot1 = euler(axes, [0.3, 0.4, 0.5]);
+
+        // Different but equivalent triples
+        let rot2 = euler(axes, [0.3 + PI, PI - 0.4, 0.5 - PI]);
+        let rot3 = euler(axes, [0.3 - TAU, 0.4, 0.5 + TAU]);
+        assert!(rot1.is_close_euler(rot2, EulerOrder::Zyx, 1e-9));
+        assert!(rot1.is_close_euler(rot3, EulerOrder::Zyx, 1e-9));
+
+        let rot4 = euler(axes, [0.3, 0.4, 0.51]);
+        assert!(!rot1.is_close_euler(rot4, EulerOrder::Zyx, 1e-3));
+        assert!(rot1.is_close_euler(rot4, EulerOrder::Zyx, 2e-2));
+
+        // Gimbal lock, where only the sum of the outer angles matters
+        let locked1 = euler(axes, [0.3, FRAC_PI_2, 0.5]);
+        let locked2 = euler(axes, [0.8, FRAC_PI_2, 0.0]);
+        let locked3 = euler(axes, [0.3, FRAC_PI_2, 0.6]);
+        assert!(locked1.is_close_euler(locked2, EulerOrder::Zyx, 1e-6));
+        assert!(!locked1.is_close_euler(locked3, EulerOrder::Zyx, 1e-3));
+    }
+
     #[test]
     fn scale() {
         let scale1 = Scale::new(2.0);
@@ -596,6 +2555,15 @@ mod tests {
         assert_is_close!(scale1, scale2);
     }
 
+    #[test]
+    fn scale_identity() {
+        use super::ScaleIsClose as _;
+
+        assert!(Scale::new(1.000_000_1_f32).is_close_identity());
+        assert!(!Scale::new(2.0_f32).is_close_identity());
+        assert!(!Scale::new(1.000_000_1_f64).is_close_identity());
+    }
+
     #[test]
     fn side_offsets_2d() {
         let offset1 =
@@ -606,6 +2574,20 @@ mod tests {
         assert_is_close!(offset1, offset2);
     }
 
+    #[test]
+    fn side_offsets_2d_uniform() {
+        use super::SideOffsets2DIsClose as _;
+
+        let offset1 = SideOffsets2D::new_all_same(0.1 + 0.2);
+        let offset2 = SideOffsets2D::new(0.3, 0.1 + 0.2, 0.3, 0.6 / 2.0);
+        let offset3 = SideOffsets2D::new(0.3, 0.4, 0.3, 0.4);
+
+        assert!(offset1.is_close_uniform(1e-9, 1e-9));
+        assert!(offset2.is_close_uniform(1e-9, 1e-9));
+        assert!(!offset3.is_close_uniform(1e-9, 1e-9));
+        assert!(offset3.is_close_uniform(0.0, 0.2));
+    }
+
     #[test]
     fn size_2d() {
         let size1 = Size2D::new(2.0, 3.0);
@@ -614,6 +2596,24 @@ mod tests {
         assert_is_close!(size1, size2);
     }
 
+    #[test]
+    fn size_2d_area() {
+        use super::Size2DIsClose as _;
+
+        let size = Size2D::new(4.0, 9.0);
+
+        assert!(size.is_close_area(Size2D::new(6.0, 6.0), 1e-9));
+        assert!(size.is_close_area(Size2D::new(36.0, 1.0), 1e-9));
+        assert!(size.is_close_area(Size2D::new(0.1 + 0.2, 120.0), 1e-9));
+        assert!(!size.is_close_area(Size2D::new(6.0, 6.1), 1e-3));
+        assert!(size.is_close_area(Size2D::new(6.0, 6.1), 0.02));
+
+        // The area of each overflows u32
+        let large = Size2D::new(100_000_u32, 80_000);
+        assert!(large.is_close_area(Size2D::new(200_000, 40_000), 0.0));
+        assert!(!large.is_close_area(Size2D::new(200_000, 40_001), 1e-9));
+    }
+
     #[test]
     fn size_3d() {
         let size1 = Size3D::new(2.0, 3.0, PI);
@@ -636,6 +2636,148 @@ mod tests {
         assert_is_close!(xform1, xform2);
     }
 
+    #[test]
+    fn transform_2d_inverse_of() {
+        use super::Transform2DIsClose as _;
+
+        let xform1 = Transform2D::scale(0.5, 0.25)
+            .then_translate(Vector2D::splat(1.0))
+            .then_rotate(Angle::radians(FRAC_PI_3));
+        let xform2 = Transform2D::rotation(-Angle::degrees(60.0))
+            .then_translate(-Vector2D::splat(1.0))
+            .then_scale(2.0, 4.0);
+
+        assert!(xform1.is_close_inverse_of(xform2, 1e-9, 1e-9));
+        assert!(xform2.is_close_inverse_of(xform1, 1e-9, 1e-9));
+        assert!(!xform1.is_close_inverse_of(xform1, 1e-9, 1e-9));
+        assert!(!xform1.is_close_inverse_of(xform2.then_scale(1.01, 1.0), 1e-9, 1e-9));
+    }
+
+    #[test]
+    fn transform_2d_on_points() {
+        use super::Transform2DIsClose as _;
+        use crate::IsClose as _;
+
+        // A shear along the x axis leaves points on the x axis unchanged
+        let xform1 = Transform2D::translation(1.0, 2.0);
+        let xform2 =
+            Transform2D::new(1.0, 0.0, 0.5, 1.0, 0.0, 0.0).then_translate(Vector2D::new(1.0, 2.0));
+        let on_axis = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(-3.0, 0.0),
+        ];
+        let off_axis = [Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)];
+
+        assert!(!xform1.is_close(xform2));
+        assert!(xform1.is_close_on_points(xform2, &on_axis, 1e-9, 1e-9));
+        assert!(!xform1.is_close_on_points(xform2, &off_axis, 1e-9, 1e-9));
+        assert!(xform1.is_close_on_points(xform2, &[], 0.0, 0.0));
+    }
+
+    #[test]
+    fn transform_2d_determinant() {
+        use super::Transform2DIsClose as _;
+        use crate::IsClose as _;
+
+        let scale = Transform2D::scale(2.0, 3.0);
+        let sheared = Transform2D::new(2.0, 0.0, 1.5, 3.0, 0.0, 0.0);
+        let rotated = scale
+            .then_rotate(Angle::radians(FRAC_PI_3))
+            .then_translate(Vector2D::new(5.0, 5.0));
+
+        assert!(!scale.is_close(sheared));
+        assert!(scale.is_close_determinant(sheared, 1e-9));
+        assert!(scale.is_close_determinant(rotated, 1e-9));
+        assert!(!scale.is_close_determinant(Transform2D::scale(2.0, 3.1), 1e-3));
+
+        // Reflections flip the sign of the determinant
+        assert!(!scale.is_close_determinant(Transform2D::scale(-2.0, 3.0), 1e-3));
+    }
+
+    #[test]
+    fn transform_2d_frobenius() {
+        use crate::{FrobeniusIsClose as _, IsClose as _};
+
+        let transform1 = Transform2D::new(1e3, 0.0, 0.0, 1e3, 1.0, 2.0);
+        let transform2 = Transform2D::new(1e3, 0.0, 0.0, 1e3, 1.0 + 1e-4, 2.0);
+
+        assert!(!transform1.is_close_rel_tol(transform2, 1e-6));
+        assert!(transform1.is_close_frobenius(transform2, 1e-6, 0.0));
+        assert!(!transform1.is_close_frobenius(transform2, 1e-9, 0.0));
+    }
+
+    #[test]
+    fn transform_2d_identity() {
+        use super::Transform2DIsClose as _;
+
+        let transform = Transform2D::rotation(Angle::radians(FRAC_PI_3)).then_scale(2.0, 3.0);
+
+        assert!(transform
+            .then(&transform.inverse().unwrap())
+            .is_close_identity());
+        assert!(!transform.is_close_identity());
+    }
+
+    #[test]
+    fn transform_3d_orthonormal() {
+        use super::Transform3DIsClose as _;
+
+        let rotation =
+            Rotation3D::around_axis(Vector3D::new(1.0, 2.0, 3.0), Angle::radians(FRAC_PI_3))
+                .to_transform()
+                .then_translate(Vector3D::new(1.0, 2.0, 3.0));
+        let scaled = Transform3D::scale(1.0, 1.0, 1.1).then(&rotation);
+        let sheared = Transform3D::new(
+            1.0, 0.1, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        );
+
+        assert!(rotation.is_close_orthonormal(1e-9));
+        assert!(!scaled.is_close_orthonormal(1e-3));
+        assert!(scaled.is_close_orthonormal(0.5));
+        assert!(!sheared.is_close_orthonormal(1e-3));
+    }
+
+    #[test]
+    fn transform_3d_projective() {
+        use super::Transform3DIsClose as _;
+        use crate::IsClose as _;
+
+        let proj = Transform3D::perspective(5.0)
+            .then(&Transform3D::ortho(-1.0, 1.0, -1.0, 1.0, 0.1, 100.0));
+        let scaled = Transform3D::from_array(proj.to_array().map(|x| x * 2.0));
+        let negated = Transform3D::from_array(proj.to_array().map(|x| -x));
+        let other = proj.then_translate(Vector3D::new(0.1, 0.0, 0.0));
+
+        assert!(!proj.is_close(scaled));
+        assert!(proj.is_close_projective(scaled, 1e-9, 1e-12));
+        assert!(scaled.is_close_projective(proj, 1e-9, 1e-12));
+        assert!(proj.is_close_projective(negated, 1e-9, 1e-12));
+        assert!(!proj.is_close_projective(other, 1e-9, 1e-12));
+
+        let zero = Transform3D::from_array([0.0; 16]);
+        assert!(!zero.is_close_projective(zero, 1.0, 1.0));
+    }
+
+    #[test]
+    fn transform_3d_translation() {
+        use super::Transform3DIsClose as _;
+        use crate::IsClose as _;
+
+        let xlate = Vector3D::new(1.0, 2.0, 3.0);
+        let xform1 =
+            Transform3D::rotation(1.0, 2.0, 3.0, Angle::radians(FRAC_PI_3)).then_translate(xlate);
+        let xform2 = Transform3D::scale(2.0, 0.5, 1.0)
+            .then_rotate(0.0, 0.0, 1.0, Angle::radians(FRAC_PI_2))
+            .then_translate(Vector3D::new(0.1 + 0.2, 2.0, 3.0) + Vector3D::new(0.7, 0.0, 0.0));
+
+        assert!(!xform1.is_close(xform2));
+        assert!(xform1.is_close_translation(xform2, 1e-9, 0.0));
+        assert!(xform1.is_close_translation(Transform3D::translation(1.0, 2.0, 3.0), 1e-9, 0.0));
+        assert!(!xform1.is_close_translation(xform2.then_translate(xlate * 1e-3), 1e-9, 0.0));
+        assert!(xform1.is_close_translation(xform2.then_translate(xlate * 1e-3), 0.0, 4e-3));
+    }
+
     #[test]
     fn transform_3d() {
         let xform1 = Transform3D::scale(0.5, 0.25, 0.5).then_translate(Vector3D::splat(1.0));
@@ -648,6 +2790,95 @@ mod tests {
         assert_is_close!(xform1, xform2);
     }
 
+    #[test]
+    fn transform_3d_decomposed() {
+        use euclid::Angle;
+
+        use super::Transform3DIsClose as _;
+        use crate::IsClose as _;
+
+        let axis = Vector3D::new(1.0, 2.0, 3.0);
+        let rot = Rotation3D::around_axis(axis, Angle::radians(FRAC_PI_3));
+        let neg_rot = Rotation3D::quaternion(-rot.i, -rot.j, -rot.k, -rot.r);
+        let transform1 = Transform3D::scale(2.0, 3.0, 4.0)
+            .then(&rot.to_transform())
+            .then_translate(Vector3D::new(1.0, 2.0, 3.0));
+        let transform2 = Transform3D::scale(2.0, 3.0, 4.0)
+            .then(&Rotation3D::around_axis(axis, Angle::radians(FRAC_PI_3 + 1e-3)).to_transform())
+            .then(&Transform3D::translation(1.0, 2.0, 3.0));
+        let transform3 = Transform3D::scale(2.0, 3.0, 4.1)
+            .then(&rot.to_transform())
+            .then_translate(Vector3D::new(1.0, 2.0, 3.0));
+        let transform4 = Transform3D::scale(-2.0, -3.0, -4.0)
+            .then(&rot.to_transform())
+            .then_translate(Vector3D::new(1.0, 2.0, 3.0));
+        let transform5 = Transform3D::scale(2.0, 3.0, 4.0)
+            .then(&neg_rot.to_transform())
+            .then_translate(Vector3D::new(1.0, 2.0, 3.1));
+
+        assert!(!transform1.is_close_decomposed(transform2, 1e-9, 1e-9));
+        assert!(transform1.is_close_decomposed(transform2, 1e-2, 1e-2));
+        assert!(!transform1.is_close_decomposed(transform3, 1e-9, 1e-9));
+        assert!(!transform1.is_close_decomposed(transform4, 1e-9, 1e-9));
+        assert!(transform4.is_close_decomposed(transform4, 1e-9, 1e-9));
+        assert!(!transform1.is_close_decomposed(transform5, 1e-9, 1e-9));
+
+        // Negating the quaternion gives the same rotation
+        let transform6 = Transform3D::scale(2.0, 3.0, 4.0)
+            .then(&neg_rot.to_transform())
+            .then(&Transform3D::translation(1.0, 2.0, 3.0));
+        assert!(transform1.is_close_decomposed(transform6, 1e-9, 1e-9));
+        assert!(transform1.is_close(transform6));
+    }
+
+    #[test]
+    fn transform_3d_frobenius() {
+        use crate::{FrobeniusIsClose as _, IsClose as _};
+
+        let transform1 =
+            Transform3D::scale(1e3, 1e3, 1e3).then_translate(Vector3D::new(1.0, 2.0, 3.0));
+        let transform2 =
+            Transform3D::scale(1e3, 1e3, 1e3).then_translate(Vector3D::new(1.0, 2.0, 3.0 + 1e-4));
+
+        assert!(!transform1.is_close_rel_tol(transform2, 1e-6));
+        assert!(transform1.is_close_frobenius(transform2, 1e-6, 0.0));
+        assert!(!transform1.is_close_frobenius(transform2, 1e-9, 0.0));
+    }
+
+    #[test]
+    fn transform_3d_identity() {
+        use super::Transform3DIsClose as _;
+
+        let transform = Transform3D::rotation(1.0, 2.0, 3.0, Angle::radians(FRAC_PI_3))
+            .then_translate(Vector3D::new(1.0, 2.0, 3.0));
+
+        assert!(transform
+            .then(&transform.inverse().unwrap())
+            .is_close_identity());
+        assert!(!transform.is_close_identity());
+    }
+
+    #[test]
+    fn transform_3d_zero() {
+        use crate::IsCloseToZero as _;
+
+        let transform = Transform3D::rotation(1.0, 2.0, 3.0, Angle::radians(FRAC_PI_3))
+            .then_translate(Vector3D::new(1.0, 2.0, 3.0));
+        let mut residual = transform.then(&transform.inverse().unwrap()).to_array();
+        for (x, identity) in residual
+            .iter_mut()
+            .zip(Transform3D::<f64>::identity().to_array())
+        {
+            *x -= identity;
+        }
+        let mut residual = Transform3D::from_array(residual);
+
+        assert!(residual.is_close_to_zero(1e-9));
+        assert!(!Transform3D::<f64>::identity().is_close_to_zero(1e-9));
+        residual.m43 += 1e-6;
+        assert!(!residual.is_close_to_zero(1e-9));
+    }
+
     #[test]
     fn translation_2d() {
         let xlate1 = Translation2D::new(2.5, 3.0);
@@ -680,4 +2911,213 @@ mod tests {
 
         assert_is_close!(xlate1, xlate2);
     }
+
+    #[test]
+    fn vector_2d_direction() {
+        use super::Vector2DIsClose as _;
+
+        let vec1 = Vector2D::new(1.0, 2.0);
+
+        assert!(vec1.is_close_direction(Vector2D::new(2.0, 4.0), 1e-9, 1e-9));
+        assert!(!vec1.is_close_direction(Vector2D::new(-1.0, -2.0), 1e-9, 1e-9));
+        assert!(!vec1.is_close_direction(Vector2D::new(2.0, 1.0), 1e-9, 1e-9));
+        assert!(!vec1.is_close_direction(Vector2D::zero(), 1e-9, 1e-9));
+        assert!(Vector2D::<f64>::zero().is_close_direction(Vector2D::zero(), 1e-9, 1e-9));
+    }
+
+    #[test]
+    fn vector_2d_parallel() {
+        use super::Vector2DIsClose as _;
+
+        let vec1 = Vector2D::new(1.0, 2.0);
+
+        assert!(vec1.is_close_parallel(Vector2D::new(-2.0, -4.0), 1e-9));
+        assert!(vec1.is_close_parallel(Vector2D::new(0.5, 1.0 + 1e-6), 1e-3));
+        assert!(!vec1.is_close_parallel(Vector2D::new(2.0, 1.0), 1e-3));
+        assert!(vec1.is_close_parallel(Vector2D::zero(), 1e-9));
+    }
+
+    #[test]
+    fn vector_2d_perpendicular() {
+        use super::Vector2DIsClose as _;
+
+        let vec1 = Vector2D::new(1.0, 0.0);
+
+        assert!(vec1.is_close_perpendicular(Vector2D::new(0.0, 1.0), 1e-9));
+        assert!(vec1.is_close_perpendicular(Vector2D::new(1e-6, -2.0), 1e-3));
+        assert!(!vec1.is_close_perpendicular(Vector2D::new(1.0, 1.0), 1e-3));
+        assert!(vec1.is_close_perpendicular(Vector2D::zero(), 1e-9));
+    }
+
+    #[test]
+    fn vector_2d_polar() {
+        use super::Vector2DIsClose as _;
+
+        let vec1 = Vector2D::new(3.0, 4.0);
+
+        assert!(vec1.is_close_polar(Vector2D::new(4.0, 3.0), 1e-9, 0.3));
+        assert!(!vec1.is_close_polar(Vector2D::new(4.0, 3.0), 1e-9, 0.2));
+        assert!(vec1.is_close_polar(Vector2D::new(3.3, 4.4), 0.2, 1e-9));
+        assert!(!vec1.is_close_polar(Vector2D::new(3.3, 4.4), 1e-2, 1e-9));
+    }
+
+    #[test]
+    fn vector_2d_projection() {
+        use super::Vector2DIsClose as _;
+
+        let velocity = Vector2D::new(3.0, 4.0);
+        let x_axis = Vector2D::new(1.0, 0.0);
+
+        assert!(velocity.is_close_projection(x_axis, 3.0, 1e-9));
+        assert!(velocity.is_close_projection(x_axis * 5.0, 3.0, 1e-9));
+        assert!(velocity.is_close_projection(-x_axis, -3.0, 1e-9));
+        assert!(!velocity.is_close_projection(x_axis, 3.1, 1e-2));
+        assert!(velocity.is_close_projection(x_axis, 3.1, 0.2));
+        assert!(velocity.is_close_projection(Vector2D::new(1.0, 1.0), 7.0 / 2.0_f64.sqrt(), 1e-9));
+        assert!(!velocity.is_close_projection(Vector2D::zero(), 0.0, 1.0));
+    }
+
+    #[test]
+    fn vector_3d_direction() {
+        use super::Vector3DIsClose as _;
+
+        let vec1 = Vector3D::new(1.0, 2.0, 3.0);
+
+        assert!(vec1.is_close_direction(Vector3D::new(2.0, 4.0, 6.0), 1e-9, 1e-9));
+        assert!(vec1.is_close_direction(Vector3D::new(0.1, 0.2, 0.3), 1e-9, 1e-9));
+        assert!(!vec1.is_close_direction(Vector3D::new(-1.0, -2.0, -3.0), 1e-9, 1e-9));
+        assert!(!vec1.is_close_direction(Vector3D::new(3.0, 2.0, 1.0), 1e-9, 1e-9));
+        assert!(!vec1.is_close_direction(Vector3D::zero(), 1e-9, 1e-9));
+    }
+
+    #[test]
+    fn vector_3d_parallel() {
+        use super::Vector3DIsClose as _;
+
+        let vec1 = Vector3D::new(1.0, 2.0, 3.0);
+
+        assert!(vec1.is_close_parallel(Vector3D::new(-2.0, -4.0, -6.0), 1e-9));
+        assert!(vec1.is_close_parallel(Vector3D::new(2.0, 4.0, 6.0), 1e-9));
+        assert!(!vec1.is_close_direction(Vector3D::new(-2.0, -4.0, -6.0), 1e-9, 1e-9));
+        assert!(!vec1.is_close_parallel(Vector3D::new(3.0, 2.0, 1.0), 1e-3));
+    }
+
+    #[test]
+    fn vector_3d_perpendicular() {
+        use super::Vector3DIsClose as _;
+
+        let vec1 = Vector3D::new(1.0, 0.0, 0.0);
+
+        assert!(vec1.is_close_perpendicular(Vector3D::new(0.0, 1.0, 0.0), 1e-9));
+        assert!(vec1.is_close_perpendicular(Vector3D::new(0.0, 2.0, -3.0), 1e-9));
+        assert!(vec1.is_close_perpendicular(vec1.cross(Vector3D::new(1.0, 2.0, 3.0)), 1e-9));
+        assert!(!vec1.is_close_perpendicular(Vector3D::new(1.0, 1.0, 0.0), 1e-3));
+    }
+
+    #[test]
+    fn vector_3d_polar() {
+        use super::Vector3DIsClose as _;
+
+        let vec1 = Vector3D::new(1.0, 2.0, 2.0);
+
+        // Same magnitude, different direction
+        assert!(!vec1.is_close_polar(Vector3D::new(2.0, 2.0, 1.0), 1e-9, 1e-3));
+        assert!(vec1.is_close_polar(Vector3D::new(2.0, 2.0, 1.0), 1e-9, 0.5));
+
+        // Same direction, different magnitude
+        assert!(!vec1.is_close_polar(vec1 * 1.1, 1e-2, 1e-9));
+        assert!(vec1.is_close_polar(vec1 * 1.1, 0.1, 1e-9));
+    }
+
+    #[test]
+    fn vector_3d_spherical() {
+        use super::Vector3DIsClose as _;
+
+        let spherical = |r: f64, theta: f64, phi: f64| {
+            Vector3D::new(
+                r * theta.sin() * phi.cos(),
+                r * theta.sin() * phi.sin(),
+                r * theta.cos(),
+            )
+        };
+        let vec = spherical(2.0, FRAC_PI_3, 1.0);
+
+        assert!(vec.is_close_spherical(spherical(2.0, FRAC_PI_3, 1.001), 1e-9, 1e-9, 2e-3));
+        assert!(!vec.is_close_spherical(spherical(2.0, FRAC_PI_3, 1.001), 1e-9, 1e-9, 5e-4));
+        assert!(!vec.is_close_spherical(spherical(2.0, FRAC_PI_3 + 1e-3, 1.0), 1e-9, 5e-4, 1.0));
+        assert!(!vec.is_close_spherical(spherical(2.1, FRAC_PI_3, 1.0), 0.05, 1e-9, 1e-9));
+        assert!(vec.is_close_spherical(spherical(2.1, FRAC_PI_3, 1.0), 0.2, 1e-9, 1e-9));
+
+        // Across the wraparound of the azimuth
+        let vec = spherical(1.0, FRAC_PI_2, PI - 1e-4);
+        assert!(vec.is_close_spherical(spherical(1.0, FRAC_PI_2, -PI + 1e-4), 1e-9, 1e-9, 3e-4));
+        assert!(!vec.is_close_spherical(spherical(1.0, FRAC_PI_2, -PI + 1e-4), 1e-9, 1e-9, 1e-4));
+
+        // The azimuth is undefined on the z axis
+        assert!(Vector3D::new(0.0, 0.0, 1.0).is_close_spherical(
+            spherical(1.0, 1e-4, 2.0),
+            1e-9,
+            2e-4,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn vector_3d_vecrel() {
+        use super::Vector3DIsClose as _;
+        use crate::IsClose as _;
+
+        let vec1 = Vector3D::new(1e3, 1e-3, 0.0);
+        let vec2 = Vector3D::new(1e3, 2e-3, 1e-4);
+
+        assert!(vec1.is_close_vecrel(vec2, 1e-5, 0.0));
+        assert!(!vec1.is_close_tol(vec2, 1e-5, 0.0));
+        assert!(!vec1.is_close_vecrel(Vector3D::new(1e3 + 1.0, 1e-3, 0.0), 1e-5, 0.0));
+        assert!(Vector3D::zero().is_close_vecrel(Vector3D::splat(1e-9), 0.0, 1e-6));
+    }
+
+    #[test]
+    fn vector_3d_cross() {
+        use super::Vector3DIsClose as _;
+
+        let vec = Vector3D::new(2.5, 4.0, -1.0);
+        let a = Vector3D::new(-1.0, 1.0, 1.5);
+        let b = Vector3D::new(2.0, -1.0, 1.0);
+
+        assert!(vec.is_close_cross(a, b, 1e-9, 0.0));
+        assert!(!vec.is_close_cross(b, a, 1e-9, 0.0));
+        assert!((-vec).is_close_cross(b, a, 1e-9, 0.0));
+        assert!(!vec.is_close_cross(a, b * 1.01, 1e-3, 0.0));
+    }
+
+    #[test]
+    fn vector_3d_zero() {
+        use crate::IsCloseToZero as _;
+
+        let a = Vector3D::new(0.1, 0.2, 0.3);
+        let b = Vector3D::new(0.3, 0.1, 0.2);
+
+        assert!((a.cross(b) + b.cross(a)).is_close_to_zero(1e-12));
+        assert!((a + b - Vector3D::new(0.4, 0.3, 0.5)).is_close_to_zero(1e-12));
+        assert!(!Vector3D::new(0.0, 0.0, 1e-6).is_close_to_zero(1e-9));
+    }
+
+    #[test]
+    fn vector_3d_orthonormal_basis() {
+        use super::is_close_orthonormal_basis;
+
+        let rot = Rotation3D::around_axis(Vector3D::new(1.0, 2.0, 3.0), Angle::radians(FRAC_PI_3));
+        let basis = [
+            rot.transform_vector3d(Vector3D::new(1.0, 0.0, 0.0)),
+            rot.transform_vector3d(Vector3D::new(0.0, 1.0, 0.0)),
+            rot.transform_vector3d(Vector3D::new(0.0, 0.0, 1.0)),
+        ];
+        let skewed = [basis[0], basis[1] + basis[0] * 0.1, basis[2]];
+        let scaled = [basis[0], basis[1], basis[2] * 1.1];
+
+        assert!(is_close_orthonormal_basis(basis, 1e-9));
+        assert!(!is_close_orthonormal_basis(skewed, 1e-3));
+        assert!(!is_close_orthonormal_basis(scaled, 1e-3));
+        assert!(is_close_orthonormal_basis(scaled, 0.5));
+    }
 }
@@ -0,0 +1,118 @@
+use core::borrow::Borrow;
+
+use fixed::types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+use fixed::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+
+use crate::{IsClose, Zero};
+
+// Comparisons are done using saturating fixed point arithmetic rather than
+// converting to floats, so they are exact and don't depend on std or libm.
+// Fixed point values have a uniform absolute precision, so the default
+// tolerances only allow for a single rounding error.
+
+macro_rules! impl_signed {
+    ($($fixed:ident($leq:ident)),+) => {$(
+        impl<Frac: $leq> Zero for $fixed<Frac> {
+            const ZERO: Self = Self::ZERO;
+        }
+
+        impl<Frac: $leq> IsClose for $fixed<Frac> {
+            const ABS_TOL: Self = Self::DELTA;
+            const REL_TOL: Self = Self::ZERO;
+
+            #[inline]
+            fn is_close_tol(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<Self>,
+                abs_tol: impl Borrow<Self>,
+            ) -> bool {
+                let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+                if *rel_tol < Self::ZERO || *abs_tol < Self::ZERO {
+                    return false;
+                }
+                let (rel_tol, abs_tol) = (rel_tol.unsigned_abs(), abs_tol.unsigned_abs());
+                let tol = Ord::max(self.unsigned_abs(), other.unsigned_abs())
+                    .saturating_mul(rel_tol)
+                    .saturating_add(abs_tol);
+                self.abs_diff(*other) <= tol
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_unsigned {
+    ($($fixed:ident($leq:ident)),+) => {$(
+        impl<Frac: $leq> Zero for $fixed<Frac> {
+            const ZERO: Self = Self::ZERO;
+        }
+
+        impl<Frac: $leq> IsClose for $fixed<Frac> {
+            const ABS_TOL: Self = Self::DELTA;
+            const REL_TOL: Self = Self::ZERO;
+
+            #[inline]
+            fn is_close_tol(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<Self>,
+                abs_tol: impl Borrow<Self>,
+            ) -> bool {
+                let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+                let tol = Ord::max(*self, *other)
+                    .saturating_mul(*rel_tol)
+                    .saturating_add(*abs_tol);
+                self.abs_diff(*other) <= tol
+            }
+        }
+    )+};
+}
+
+impl_signed!(
+    FixedI8(LeEqU8),
+    FixedI16(LeEqU16),
+    FixedI32(LeEqU32),
+    FixedI64(LeEqU64),
+    FixedI128(LeEqU128)
+);
+
+impl_unsigned!(
+    FixedU8(LeEqU8),
+    FixedU16(LeEqU16),
+    FixedU32(LeEqU32),
+    FixedU64(LeEqU64),
+    FixedU128(LeEqU128)
+);
+
+#[cfg(test)]
+mod tests {
+    use fixed::types::{I16F16, U16F16};
+
+    use super::*;
+
+    #[test]
+    fn fixed_i_is_close_tol() {
+        let fix1 = I16F16::from_num(0.1) + I16F16::from_num(0.2);
+        let fix2 = I16F16::from_num(0.3);
+
+        assert!(fix1.is_close(fix2));
+        assert!(fix1.is_close_abs_tol(fix2 + I16F16::from_num(1e-3), I16F16::from_num(1e-2)));
+        assert!(!fix1.is_close_abs_tol(fix2 + I16F16::from_num(1e-2), I16F16::from_num(1e-3)));
+        assert!(I16F16::MIN.is_close_rel_tol(I16F16::MIN + I16F16::ONE, I16F16::from_num(1e-4)));
+        assert!(!I16F16::MIN.is_close(I16F16::MAX));
+    }
+
+    #[test]
+    fn fixed_u_is_close_tol() {
+        let fix1 = U16F16::from_num(0.1) + U16F16::from_num(0.2);
+        let fix2 = U16F16::from_num(0.3);
+
+        assert!(fix1.is_close(fix2));
+        assert!(fix1.is_close_abs_tol(fix2 + U16F16::from_num(1e-3), U16F16::from_num(1e-2)));
+        assert!(!fix1.is_close_abs_tol(fix2 + U16F16::from_num(1e-2), U16F16::from_num(1e-3)));
+        assert!(U16F16::MAX.is_close_rel_tol(U16F16::MAX - U16F16::ONE, U16F16::from_num(1e-4)));
+    }
+}
@@ -0,0 +1,490 @@
+use core::borrow::Borrow;
+use core::fmt::{self, Write};
+
+use crate::{Abs, IsClose, Log10, Sqrt, TolOrd, Zero};
+
+/// Which tolerance terms a comparison is within, as returned by
+/// [`FloatIsClose::which_tol`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TolKind {
+    /// Only within the relative tolerance
+    Relative,
+    /// Only within the absolute tolerance
+    Absolute,
+    /// Within both the relative and absolute tolerances
+    Both,
+    /// Within neither the relative nor absolute tolerance
+    Neither,
+}
+
+/// Trait providing additional comparison modes for floating point values
+pub trait FloatIsClose: IsClose + Zero + Sized {
+    /// Check if two values are approximately equal using the given relative
+    /// and absolute tolerances, scaling the relative tolerance by the
+    /// geometric mean of the magnitudes of the two values
+    ///
+    /// [`IsClose::is_close_tol`] scales the relative tolerance by the larger
+    /// of the two magnitudes, so the effective tolerance is biased towards the
+    /// larger value. The geometric mean lies between the two magnitudes and is
+    /// stricter for values far apart, which some numerical libraries prefer
+    /// for values spanning many orders of magnitude. The relative term
+    /// vanishes if either value is zero, so a nonzero absolute tolerance is
+    /// needed when comparing against zero.
+    fn is_close_geomean(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Self>,
+        abs_tol: impl Borrow<Self>,
+    ) -> bool;
+
+    /// Check if two values are approximately equal using the given relative
+    /// and absolute tolerances, additionally requiring them to have the same
+    /// sign
+    ///
+    /// Two nonzero values on opposite sides of zero are never considered
+    /// close, even if their difference is within the absolute tolerance. A
+    /// value of exactly zero is treated as having the same sign as any other
+    /// value, so it can still be close to small positive or negative values.
+    fn is_close_signed(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Self>,
+        abs_tol: impl Borrow<Self>,
+    ) -> bool;
+
+    /// Check if two values are approximately equal using the given relative
+    /// and absolute tolerances, scaling both tolerances by the number of terms
+    /// `n` that were accumulated to compute the values
+    ///
+    /// The rounding error of a naive sum of `n` terms grows linearly with `n`
+    /// in the worst case, so a fixed tolerance is too strict for large sums.
+    fn is_close_scaled_by_terms(
+        &self,
+        other: impl Borrow<Self>,
+        n: usize,
+        rel_tol: impl Borrow<Self>,
+        abs_tol: impl Borrow<Self>,
+    ) -> bool;
+
+    /// Check if two values are approximately equal using the given relative
+    /// and absolute tolerances, treating subnormal values as zero
+    ///
+    /// This matches the behaviour of hardware using flush-to-zero or
+    /// denormals-are-zero modes.
+    fn is_close_ftz(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Self>,
+        abs_tol: impl Borrow<Self>,
+    ) -> bool;
+
+    /// Check if the base 10 logarithms of two values are within the given
+    /// absolute tolerance, i.e. if the values are within `tol` decades of each
+    /// other
+    ///
+    /// This is useful for quantities spanning many orders of magnitude, such
+    /// as frequencies or concentrations. Both values must be positive; this
+    /// always returns `false` otherwise.
+    fn is_close_log10(&self, other: impl Borrow<Self>, tol: impl Borrow<Self>) -> bool;
+
+    /// Check which of the given relative and absolute tolerances two values
+    /// are within, checking each tolerance independently
+    ///
+    /// This can help decide which tolerance to adjust. Since
+    /// [`IsClose::is_close_tol`] sums both tolerance terms, values can be
+    /// close even if this returns [`TolKind::Neither`].
+    fn which_tol(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Self>,
+        abs_tol: impl Borrow<Self>,
+    ) -> TolKind;
+
+    /// The error of `self` relative to the reference value `other` in
+    /// decibels, i.e. `20 * log10(|self - other| / |other|)`
+    ///
+    /// Lower values are closer, e.g. `-60.0` dB is a relative error of
+    /// `0.001`. If the reference is zero, this is negative infinity if the
+    /// values are equal and positive infinity otherwise.
+    fn rel_error_db(&self, other: impl Borrow<Self>) -> f64;
+
+    /// Check if two values are equal after rounding both to the given number
+    /// of significant digits
+    ///
+    /// Each value is rounded to the nearest decimal with `sig_digits`
+    /// significant digits, as when formatting it using `{:.N$e}` with
+    /// `N = sig_digits - 1`, and the rounded values are compared exactly.
+    /// Since the rounding is based on the exact binary value, the result only
+    /// depends on the values being compared, making it reproducible across
+    /// compilers and platforms. At least one and at most 40 significant digits
+    /// are used. Note values either side of a rounding boundary are not equal
+    /// no matter how close they are, e.g. `1.449` and `1.451` with 2 digits.
+    ///
+    /// Zeros of either sign are equal, and NaN is never equal to anything.
+    fn is_close_rounded(&self, other: impl Borrow<Self>, sig_digits: u32) -> bool;
+
+    /// Check if two values are approximately equal using the given relative
+    /// and absolute tolerances, treating values within the given noise floor
+    /// as equal
+    ///
+    /// If the magnitudes of both values are no larger than `floor` they are
+    /// indistinguishable from noise, so are close regardless of their
+    /// difference. Otherwise they are compared as with
+    /// [`IsClose::is_close_tol`].
+    fn is_close_noise_floor(
+        &self,
+        other: impl Borrow<Self>,
+        floor: impl Borrow<Self>,
+        rel_tol: impl Borrow<Self>,
+        abs_tol: impl Borrow<Self>,
+    ) -> bool;
+}
+
+/// Maximum number of significant digits used by
+/// [`FloatIsClose::is_close_rounded`]
+const MAX_SIG_DIGITS: u32 = 40;
+
+/// Fixed size buffer for formatting rounded floats without allocating
+struct RoundedBuf {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl RoundedBuf {
+    /// Format a value in scientific notation with the given number of
+    /// significant digits
+    fn new(value: f64, sig_digits: u32) -> Self {
+        let mut buf = Self {
+            bytes: [0; 64],
+            len: 0,
+        };
+        let precision = sig_digits.clamp(1, MAX_SIG_DIGITS) as usize - 1;
+        // The buffer is large enough for any f64 with MAX_SIG_DIGITS digits
+        let _ = write!(buf, "{value:.precision$e}");
+        buf
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Write for RoundedBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+macro_rules! impl_float_is_close {
+    ($($float:ty),+) => {$(
+        impl FloatIsClose for $float {
+            #[inline]
+            fn is_close_geomean(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<Self>,
+                abs_tol: impl Borrow<Self>,
+            ) -> bool {
+                let (other, rel_tol, abs_tol) =
+                    (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+                let scale = Sqrt::sqrt(&Abs::abs(self)) * Sqrt::sqrt(&Abs::abs(other));
+                let tol = scale * rel_tol + abs_tol;
+                Abs::abs(&(*self - *other)).is_within(&tol)
+            }
+
+            #[inline]
+            fn is_close_signed(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<Self>,
+                abs_tol: impl Borrow<Self>,
+            ) -> bool {
+                let other = other.borrow();
+                let opposite = (*self > Self::ZERO && *other < Self::ZERO)
+                    || (*self < Self::ZERO && *other > Self::ZERO);
+                !opposite && self.is_close_tol(other, rel_tol, abs_tol)
+            }
+
+            #[inline]
+            fn is_close_scaled_by_terms(
+                &self,
+                other: impl Borrow<Self>,
+                n: usize,
+                rel_tol: impl Borrow<Self>,
+                abs_tol: impl Borrow<Self>,
+            ) -> bool {
+                #[allow(clippy::cast_precision_loss)] // only used to scale the tolerances
+                let n = n as Self;
+                self.is_close_tol(other, n * rel_tol.borrow(), n * abs_tol.borrow())
+            }
+
+            #[inline]
+            fn is_close_ftz(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<Self>,
+                abs_tol: impl Borrow<Self>,
+            ) -> bool {
+                let ftz = |x: Self| if x.is_subnormal() { Self::ZERO } else { x };
+                ftz(*self).is_close_tol(ftz(*other.borrow()), rel_tol, abs_tol)
+            }
+
+            #[inline]
+            fn is_close_log10(&self, other: impl Borrow<Self>, tol: impl Borrow<Self>) -> bool {
+                let other = other.borrow();
+                *self > Self::ZERO
+                    && *other > Self::ZERO
+                    && Log10::log10(self).is_close_abs_tol(Log10::log10(other), tol)
+            }
+
+            #[inline]
+            fn which_tol(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<Self>,
+                abs_tol: impl Borrow<Self>,
+            ) -> TolKind {
+                match (
+                    self.is_close_rel_tol(other.borrow(), rel_tol),
+                    self.is_close_abs_tol(other.borrow(), abs_tol),
+                ) {
+                    (true, true) => TolKind::Both,
+                    (true, false) => TolKind::Relative,
+                    (false, true) => TolKind::Absolute,
+                    (false, false) => TolKind::Neither,
+                }
+            }
+
+            #[inline]
+            fn rel_error_db(&self, other: impl Borrow<Self>) -> f64 {
+                let (lhs, rhs) = (f64::from(*self), f64::from(*other.borrow()));
+                if rhs == 0.0 {
+                    return if lhs == 0.0 { f64::NEG_INFINITY } else { f64::INFINITY };
+                }
+                20.0 * Log10::log10(&Abs::abs(&((lhs - rhs) / rhs)))
+            }
+
+            #[inline]
+            fn is_close_rounded(&self, other: impl Borrow<Self>, sig_digits: u32) -> bool {
+                // Adding zero normalizes -0.0 to 0.0
+                let (lhs, rhs) = (f64::from(*self) + 0.0, f64::from(*other.borrow()) + 0.0);
+                !lhs.is_nan()
+                    && !rhs.is_nan()
+                    && RoundedBuf::new(lhs, sig_digits).as_bytes()
+                        == RoundedBuf::new(rhs, sig_digits).as_bytes()
+            }
+
+            #[inline]
+            fn is_close_noise_floor(
+                &self,
+                other: impl Borrow<Self>,
+                floor: impl Borrow<Self>,
+                rel_tol: impl Borrow<Self>,
+                abs_tol: impl Borrow<Self>,
+            ) -> bool {
+                let (other, floor) = (other.borrow(), floor.borrow());
+                (Abs::abs(self) <= *floor && Abs::abs(other) <= *floor)
+                    || self.is_close_tol(other, rel_tol, abs_tol)
+            }
+        }
+    )+};
+}
+
+impl_float_is_close!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f32_is_close_geomean() {
+        assert!(1.0_f32.is_close_geomean(1.0 + 1e-2, 1e-1, 0.0));
+        assert!(1.0_f32.is_close_tol(2.0, 0.5, 0.0));
+        assert!(!1.0_f32.is_close_geomean(2.0, 0.5, 0.0));
+        assert!(!0.0_f32.is_close_geomean(1e-3, 1.0, 0.0));
+        assert!(0.0_f32.is_close_geomean(1e-3, 1.0, 1e-2));
+    }
+
+    #[test]
+    fn f32_is_close_signed() {
+        assert!(1e-10_f32.is_close_tol(-1e-10, 0.0, 1e-6));
+        assert!(!1e-10_f32.is_close_signed(-1e-10, 0.0, 1e-6));
+        assert!(1e-10_f32.is_close_signed(2e-10, 0.0, 1e-6));
+        assert!(0.0_f32.is_close_signed(-1e-10, 0.0, 1e-6));
+        assert!(!1.0_f32.is_close_signed(2.0, 0.0, 1e-6));
+    }
+
+    #[test]
+    fn f32_is_close_scaled_by_terms() {
+        let sum: f32 = (0..1000).map(|_| 0.1).sum();
+
+        assert!(!sum.is_close(100.0));
+        assert!(sum.is_close_scaled_by_terms(100.0, 1000, f32::REL_TOL, f32::ABS_TOL));
+        assert!(!sum.is_close_scaled_by_terms(101.0, 1000, f32::REL_TOL, f32::ABS_TOL));
+    }
+
+    #[test]
+    fn f32_is_close_ftz() {
+        let subnormal = f32::MIN_POSITIVE / 2.0;
+
+        assert!(!subnormal.is_close_tol(0.0, 0.0, 0.0));
+        assert!(subnormal.is_close_ftz(0.0, 0.0, 0.0));
+        assert!(subnormal.is_close_ftz(-subnormal, 0.0, 0.0));
+        assert!(!f32::MIN_POSITIVE.is_close_ftz(0.0, 0.0, 0.0));
+        assert!(1.0_f32.is_close_ftz(1.0 + 1e-7, 1e-6, 0.0));
+    }
+
+    #[test]
+    fn f32_is_close_log10() {
+        assert!(100.0_f32.is_close_log10(105.0, 0.05));
+        assert!(!100.0_f32.is_close_log10(105.0, 0.01));
+        assert!(1e-3_f32.is_close_log10(1e3, 6.0));
+        assert!(!0.0_f32.is_close_log10(0.0, 1.0));
+        assert!(!(-100.0_f32).is_close_log10(-105.0, 1.0));
+    }
+
+    #[test]
+    fn f32_which_tol() {
+        assert_eq!(1e-8_f32.which_tol(2e-8, 1e-6, 1e-6), TolKind::Absolute);
+        assert_eq!(1e6_f32.which_tol(1e6 + 0.5, 1e-6, 1e-6), TolKind::Relative);
+        assert_eq!(1.0_f32.which_tol(1.0 + 1e-7, 1e-6, 1e-6), TolKind::Both);
+        assert_eq!(1.0_f32.which_tol(2.0, 1e-6, 1e-6), TolKind::Neither);
+    }
+
+    #[test]
+    fn f64_is_close_geomean() {
+        assert!(1.0_f64.is_close_geomean(1.0 + 1e-2, 1e-1, 0.0));
+        assert!(1e-3_f64.is_close_tol(1e3, 1.0, 0.0));
+        assert!(!1e-3_f64.is_close_geomean(1e3, 1.0, 0.0));
+        assert!(!0.0_f64.is_close_geomean(1e-3, 1.0, 0.0));
+        assert!(0.0_f64.is_close_geomean(1e-3, 1.0, 1e-2));
+    }
+
+    #[test]
+    fn f64_is_close_signed() {
+        assert!(1e-10_f64.is_close_tol(-1e-10, 0.0, 1e-6));
+        assert!(!1e-10_f64.is_close_signed(-1e-10, 0.0, 1e-6));
+        assert!(1e-10_f64.is_close_signed(2e-10, 0.0, 1e-6));
+        assert!(0.0_f64.is_close_signed(-1e-10, 0.0, 1e-6));
+        assert!(!1.0_f64.is_close_signed(2.0, 0.0, 1e-6));
+    }
+
+    #[test]
+    fn f64_is_close_scaled_by_terms() {
+        let sum: f64 = (0..1000).map(|_| 0.1).sum();
+
+        assert!(!sum.is_close_rel_tol(100.0, 1e-15));
+        assert!(sum.is_close_scaled_by_terms(100.0, 1000, 1e-15, 0.0));
+        assert!(!sum.is_close_scaled_by_terms(100.1, 1000, 1e-15, 0.0));
+    }
+
+    #[test]
+    fn f64_is_close_ftz() {
+        let subnormal = f64::MIN_POSITIVE / 2.0;
+
+        assert!(!subnormal.is_close_tol(0.0, 0.0, 0.0));
+        assert!(subnormal.is_close_ftz(0.0, 0.0, 0.0));
+        assert!(subnormal.is_close_ftz(-subnormal, 0.0, 0.0));
+        assert!(!f64::MIN_POSITIVE.is_close_ftz(0.0, 0.0, 0.0));
+        assert!(1.0_f64.is_close_ftz(1.0 + 1e-10, 1e-9, 0.0));
+    }
+
+    #[test]
+    fn f64_is_close_log10() {
+        assert!(100.0_f64.is_close_log10(105.0, 0.05));
+        assert!(!100.0_f64.is_close_log10(105.0, 0.01));
+        assert!(1e-100_f64.is_close_log10(1e100, 200.0));
+        assert!(!0.0_f64.is_close_log10(0.0, 1.0));
+        assert!(!(-100.0_f64).is_close_log10(-105.0, 1.0));
+    }
+
+    #[test]
+    fn f32_rel_error_db() {
+        assert!(1.001_f32.rel_error_db(1.0).is_close_abs_tol(-60.0, 1e-3));
+        assert!(0.9_f32.rel_error_db(1.0).is_close_abs_tol(-20.0, 1e-3));
+    }
+
+    #[test]
+    fn f64_rel_error_db() {
+        assert!(1.001_f64.rel_error_db(1.0).is_close(-60.0));
+        assert!((-999.0_f64).rel_error_db(-1000.0).is_close(-60.0));
+        assert!(2.0_f64.rel_error_db(1.0).is_close_abs_tol(0.0, 1e-12));
+        assert!(1.0_f64.rel_error_db(1.0) < -1e300);
+
+        assert!(0.0_f64.rel_error_db(0.0) < -1e300);
+        assert!(1e-300_f64.rel_error_db(0.0) > 1e300);
+        assert!(f64::NAN.rel_error_db(1.0).is_nan());
+    }
+
+    #[test]
+    fn f32_is_close_rounded() {
+        assert!(1.234_567_f32.is_close_rounded(1.234_568, 6));
+        assert!(!1.234_567_f32.is_close_rounded(1.234_568, 7));
+        assert!((-0.0_f32).is_close_rounded(0.0, 6));
+    }
+
+    #[test]
+    fn f64_is_close_rounded() {
+        // Agree to 6 significant figures, but differ beyond
+        assert!(123_456.71_f64.is_close_rounded(123_456.69, 6));
+        assert!(!123_456.71_f64.is_close_rounded(123_456.69, 8));
+        assert!(1.234_561e-20_f64.is_close_rounded(1.234_564e-20, 6));
+        assert!(!1.234_561e-20_f64.is_close_rounded(1.234_566e-20, 6));
+
+        // Either side of a rounding boundary
+        assert!(!1.449_f64.is_close_rounded(1.451, 2));
+        assert!(1.449_f64.is_close_rounded(1.451, 1));
+
+        assert!((0.1_f64 + 0.2).is_close_rounded(0.3, 16));
+        assert!(!(0.1_f64 + 0.2).is_close_rounded(0.3, 17));
+        assert!(!(0.1_f64 + 0.2).is_close_rounded(0.3, 100));
+        assert!(1.4_f64.is_close_rounded(1.0, 0));
+
+        assert!(f64::INFINITY.is_close_rounded(f64::INFINITY, 6));
+        assert!(!f64::NAN.is_close_rounded(f64::NAN, 6));
+        assert!(f64::MAX.is_close_rounded(f64::MAX, 40));
+        assert!(f64::MIN_POSITIVE.is_close_rounded(f64::MIN_POSITIVE, 40));
+    }
+
+    #[test]
+    fn f32_is_close_noise_floor() {
+        assert!(1e-7_f32.is_close_noise_floor(-5e-7, 1e-6, 1e-6, 0.0));
+        assert!(!1e-7_f32.is_close_tol(-5e-7, 1e-6, 0.0));
+        assert!(!1e-7_f32.is_close_noise_floor(5e-6, 1e-6, 1e-6, 0.0));
+    }
+
+    #[test]
+    fn f64_is_close_noise_floor() {
+        // Both below the floor, differing by a factor of 10
+        assert!(1e-12_f64.is_close_noise_floor(1e-11, 1e-9, 1e-6, 0.0));
+        assert!(!1e-12_f64.is_close_tol(1e-11, 1e-6, 0.0));
+
+        // Only one below the floor
+        assert!(!1e-12_f64.is_close_noise_floor(1e-3, 1e-9, 1e-6, 0.0));
+        assert!(1e-12_f64.is_close_noise_floor(1e-3, 1e-9, 0.0, 1e-2));
+
+        // Above the floor the normal rule applies
+        assert!(1.0_f64.is_close_noise_floor(1.0 + 1e-9, 1e-9, 1e-6, 0.0));
+        assert!(!1.0_f64.is_close_noise_floor(1.1, 1e-9, 1e-6, 0.0));
+        assert!(!f64::NAN.is_close_noise_floor(f64::NAN, 1e-9, 1e-6, 0.0));
+    }
+
+    #[test]
+    fn f64_which_tol() {
+        assert_eq!(1e-12_f64.which_tol(2e-12, 1e-9, 1e-9), TolKind::Absolute);
+        assert_eq!(1e9_f64.which_tol(1e9 + 0.5, 1e-9, 1e-9), TolKind::Relative);
+        assert_eq!(1.0_f64.which_tol(1.0 + 1e-10, 1e-9, 1e-9), TolKind::Both);
+        assert_eq!(1.0_f64.which_tol(2.0, 1e-9, 1e-9), TolKind::Neither);
+        assert_eq!(
+            1.0_f64.which_tol(1.0 + 1.5e-9, 1e-9, 1e-9),
+            TolKind::Neither
+        );
+        assert!(1.0_f64.is_close_tol(1.0 + 1.5e-9, 1e-9, 1e-9));
+    }
+}
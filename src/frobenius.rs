@@ -0,0 +1,185 @@
+use core::borrow::Borrow;
+
+use crate::{Sqrt, Zero};
+
+/// Trait for comparing arrays and matrices using the Frobenius norm
+///
+/// The Frobenius norm is the square root of the sum of the squares of every
+/// entry. Rather than comparing each entry independently, two values are
+/// close if the norm of their difference is within the relative tolerance of
+/// the larger of their norms, plus the absolute tolerance. This is standard in
+/// numerical linear algebra, since it allows proportionally larger errors in
+/// small entries of a matrix dominated by large entries.
+///
+/// This is implemented for [`f32`] and [`f64`], and for arrays and slices of
+/// other implementors, so nested arrays can be compared as matrices.
+///
+/// ```
+/// use isclose::{FrobeniusIsClose, IsClose};
+///
+/// let a = [[1e3, 0.0], [0.0, 1.0]];
+/// let b = [[1e3, 0.0], [0.0, 1.0 + 1e-4]];
+///
+/// assert!(!a[1][1].is_close_rel_tol(b[1][1], 1e-6));
+/// assert!(a.is_close_frobenius(b, 1e-6, 0.0));
+/// ```
+pub trait FrobeniusIsClose<T> {
+    /// The sum of the squares of every entry
+    fn squared_norm(&self) -> T;
+
+    /// The sum of the squares of the differences between every pair of
+    /// entries, or [`None`] if the values have different shapes
+    fn squared_distance(&self, other: &Self) -> Option<T>;
+
+    /// Check if two values are approximately equal using the given relative
+    /// and absolute tolerances, comparing the Frobenius norm of their
+    /// difference to the larger of their Frobenius norms
+    ///
+    /// Values with different shapes are never close.
+    fn is_close_frobenius(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<T>,
+        abs_tol: impl Borrow<T>,
+    ) -> bool;
+}
+
+/// Compares the Frobenius norms given the squared norms
+fn is_close_frobenius<Value, T>(lhs: &Value, rhs: &Value, rel_tol: T, abs_tol: T) -> bool
+where
+    Value: FrobeniusIsClose<T> + ?Sized,
+    T: Sqrt + Copy + PartialOrd + core::ops::Add<Output = T> + core::ops::Mul<Output = T>,
+{
+    lhs.squared_distance(rhs).map_or(false, |distance| {
+        let (lhs, rhs) = (lhs.squared_norm(), rhs.squared_norm());
+        let norm = if lhs < rhs { rhs } else { lhs };
+        Sqrt::sqrt(&distance) <= Sqrt::sqrt(&norm) * rel_tol + abs_tol
+    })
+}
+
+macro_rules! impl_frobenius_is_close {
+    ($($float:ty),+) => {$(
+        impl FrobeniusIsClose<$float> for $float {
+            #[inline]
+            fn squared_norm(&self) -> $float {
+                self * self
+            }
+
+            #[inline]
+            fn squared_distance(&self, other: &Self) -> Option<$float> {
+                Some((self - other) * (self - other))
+            }
+
+            #[inline]
+            fn is_close_frobenius(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<$float>,
+                abs_tol: impl Borrow<$float>,
+            ) -> bool {
+                is_close_frobenius(self, other.borrow(), *rel_tol.borrow(), *abs_tol.borrow())
+            }
+        }
+
+        impl<T> FrobeniusIsClose<$float> for [T]
+        where
+            T: FrobeniusIsClose<$float>,
+        {
+            #[inline]
+            fn squared_norm(&self) -> $float {
+                self.iter().map(T::squared_norm).fold(<$float>::ZERO, |acc, x| acc + x)
+            }
+
+            #[inline]
+            fn squared_distance(&self, other: &Self) -> Option<$float> {
+                if self.len() != other.len() {
+                    return None;
+                }
+                self.iter()
+                    .zip(other)
+                    .try_fold(<$float>::ZERO, |acc, (lhs, rhs)| Some(acc + lhs.squared_distance(rhs)?))
+            }
+
+            #[inline]
+            fn is_close_frobenius(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<$float>,
+                abs_tol: impl Borrow<$float>,
+            ) -> bool {
+                is_close_frobenius(self, other.borrow(), *rel_tol.borrow(), *abs_tol.borrow())
+            }
+        }
+
+        impl<T, const N: usize> FrobeniusIsClose<$float> for [T; N]
+        where
+            T: FrobeniusIsClose<$float>,
+        {
+            #[inline]
+            fn squared_norm(&self) -> $float {
+                self[..].squared_norm()
+            }
+
+            #[inline]
+            fn squared_distance(&self, other: &Self) -> Option<$float> {
+                self[..].squared_distance(&other[..])
+            }
+
+            #[inline]
+            fn is_close_frobenius(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<$float>,
+                abs_tol: impl Borrow<$float>,
+            ) -> bool {
+                is_close_frobenius(self, other.borrow(), *rel_tol.borrow(), *abs_tol.borrow())
+            }
+        }
+    )+};
+}
+
+impl_frobenius_is_close!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IsClose;
+
+    #[test]
+    fn f32_is_close_frobenius() {
+        assert!(1.0_f32.is_close_frobenius(1.0 + 1e-7, 1e-6, 0.0));
+        assert!(!1.0_f32.is_close_frobenius(1.1, 1e-6, 0.0));
+        assert!(0.0_f32.is_close_frobenius(1e-7, 0.0, 1e-6));
+    }
+
+    #[test]
+    fn f64_array_is_close_frobenius() {
+        let a = [[1e3, 0.0], [0.0, 1.0]];
+        let b = [[1e3, 0.0], [0.0, 1.0 + 1e-4]];
+
+        // The small entry is far outside its entrywise relative tolerance, but
+        // the difference is small relative to the matrix as a whole
+        assert!(!a[1][1].is_close_rel_tol(b[1][1], 1e-6));
+        assert!(a.is_close_frobenius(b, 1e-6, 0.0));
+        assert!(!a.is_close_frobenius(b, 1e-8, 0.0));
+
+        // Small errors in every entry add up, even if each entry is close
+        let c = [[1.0, 1.0], [1.0, 1.0]];
+        let d = [[1.0 + 1e-6, 1.0 - 1e-6], [1.0 + 1e-6, 1.0 - 1e-6]];
+        assert!(c
+            .iter()
+            .flatten()
+            .zip(d.iter().flatten())
+            .all(|(c, d)| c.is_close_abs_tol(d, 1e-6 + 1e-12)));
+        assert!(!c.is_close_frobenius(d, 0.0, 1e-6 + 1e-12));
+        assert!(c.is_close_frobenius(d, 0.0, 2e-6 + 1e-12));
+    }
+
+    #[test]
+    fn f64_slice_is_close_frobenius() {
+        let computed: Vec<f64> = vec![1e3, 1.0, 2.0];
+
+        assert!(computed[..].is_close_frobenius(&[1e3, 1.0 + 1e-4, 2.0][..], 1e-6, 0.0));
+        assert!(!computed[..].is_close_frobenius(&[1e3, 1.0][..], 1.0, 1.0));
+    }
+}
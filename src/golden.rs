@@ -0,0 +1,111 @@
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+
+use crate::{IsClose, Zero};
+
+/// Assert that a computed value is approximately equal to the expected value
+/// stored as JSON in a golden file, using the given relative and absolute
+/// tolerances
+///
+/// The expected value is deserialized from the file into the same type as the
+/// computed value, and compared as with
+/// [`assert_is_close_tol!`][crate::assert_is_close_tol]. The path of the file
+/// is included in the panic message on failure.
+///
+/// ```no_run
+/// use isclose::assert_close_to_golden;
+///
+/// let computed: Vec<f64> = (1..=3).map(|i| f64::from(i).sqrt()).collect();
+/// assert_close_to_golden("tests/golden/sqrt.json", &computed[..], 1e-9, 0.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the values are not approximately equal, or if the golden file
+/// can't be read or deserialized.
+#[track_caller]
+pub fn assert_close_to_golden<Value, Tolerance>(
+    path: impl AsRef<Path>,
+    computed: &Value,
+    rel_tol: impl Borrow<Tolerance>,
+    abs_tol: impl Borrow<Tolerance>,
+) where
+    Value: IsClose<Tolerance> + ToOwned + Debug + ?Sized,
+    Value::Owned: DeserializeOwned,
+    Tolerance: Zero + Debug,
+{
+    let (path, rel_tol, abs_tol) = (path.as_ref(), rel_tol.borrow(), abs_tol.borrow());
+    let expected: Value::Owned = match std::fs::read_to_string(path)
+        .map_err(|err| err.to_string())
+        .and_then(|json| serde_json::from_str(&json).map_err(|err| err.to_string()))
+    {
+        Ok(expected) => expected,
+        Err(err) => panic!("failed to load golden file {}: {}", path.display(), err),
+    };
+
+    if !computed.is_close_tol(expected.borrow(), rel_tol, abs_tol) {
+        crate::macros::assert_failed(
+            computed,
+            expected.borrow(),
+            Some(rel_tol),
+            Some(abs_tol),
+            Some(format_args!("golden file {}", path.display())),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn golden_file(name: &str, json: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("isclose-{}-{}.json", std::process::id(), name));
+        std::fs::write(&path, json).unwrap();
+        path
+    }
+
+    #[test]
+    fn golden() {
+        let path = golden_file("golden", "[0.3, 0.6, 1.0]");
+
+        assert_close_to_golden(&path, &[0.1 + 0.2, 0.2 + 0.4, 1.0][..], 1e-9, 0.0);
+        assert_close_to_golden(&path, &vec![0.3_f32, 0.6, 1.0][..], 1e-6, 0.0);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn golden_error() {
+        let path = golden_file("golden_error", "0.5");
+
+        let err = std::panic::catch_unwind(|| assert_close_to_golden(&path, &0.6, 1e-3, 0.0))
+            .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert_eq!(
+            msg,
+            &format!(
+                "assertion `left ~= right` failed: golden file {}
+    left: 0.6
+   right: 0.5
+ rel tol: 0.001
+ abs tol: 0.0",
+                path.display()
+            )
+        );
+
+        std::fs::remove_file(&path).unwrap();
+
+        let err = std::panic::catch_unwind(|| assert_close_to_golden(&path, &0.6, 1e-3, 0.0))
+            .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert!(msg.starts_with(&format!("failed to load golden file {}: ", path.display())));
+    }
+}
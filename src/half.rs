@@ -1,11 +1,23 @@
 use core::borrow::Borrow;
 
-use crate::{IsClose, Zero};
+use crate::{HasZero, IsClose, Zero};
 
 use half::{bf16, f16};
 
 impl Zero for f16 {
     const ZERO: Self = Self::ZERO;
+
+    #[inline]
+    fn from_tol_override(tol: f64) -> Option<Self> {
+        Some(Self::from_f64(tol))
+    }
+}
+
+impl HasZero for f16 {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
 }
 
 impl IsClose for f16 {
@@ -27,6 +39,18 @@ impl IsClose for f16 {
 
 impl Zero for bf16 {
     const ZERO: Self = Self::ZERO;
+
+    #[inline]
+    fn from_tol_override(tol: f64) -> Option<Self> {
+        Some(Self::from_f64(tol))
+    }
+}
+
+impl HasZero for bf16 {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
 }
 
 impl IsClose for bf16 {
@@ -0,0 +1,101 @@
+use alloc::vec;
+
+use crate::IsClose;
+
+/// Check if the histograms of two samples are approximately equal using the
+/// given relative and absolute tolerances
+///
+/// Both samples are divided into `bins` equally sized bins over the given
+/// range, and the number of values in each bin are compared as floats. Values
+/// outside of the range are ignored. Since the counts are compared directly,
+/// the samples should usually be the same size.
+///
+/// ```
+/// use isclose::histograms_close;
+///
+/// let a = [0.1, 0.2, 0.6, 0.7, 0.8];
+/// let b = [0.3, 0.4, 0.45, 0.9, 1.0];
+///
+/// assert!(histograms_close(&a, &b, 2, (0.0, 1.0), 0.0, 1.0));
+/// assert!(!histograms_close(&a, &b, 2, (0.0, 1.0), 0.0, 0.0));
+/// ```
+#[must_use]
+pub fn histograms_close(
+    a: &[f64],
+    b: &[f64],
+    bins: usize,
+    range: (f64, f64),
+    rel_tol: f64,
+    abs_tol: f64,
+) -> bool {
+    let (min, max) = range;
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )] // values are in the range 0..=bins
+    let bin = |x: f64| {
+        (min..=max)
+            .contains(&x)
+            .then(|| ((((x - min) / (max - min)) * bins as f64) as usize).min(bins - 1))
+    };
+
+    let mut counts = vec![(0_usize, 0_usize); bins];
+    if bins > 0 {
+        for i in a.iter().filter_map(|&x| bin(x)) {
+            counts[i].0 += 1;
+        }
+        for i in b.iter().filter_map(|&x| bin(x)) {
+            counts[i].1 += 1;
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)] // counts are only compared approximately
+    counts
+        .into_iter()
+        .all(|(a, b)| (a as f64).is_close_tol(b as f64, rel_tol, abs_tol))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histograms_close_samples() {
+        // Low discrepancy sequences which are approximately uniform
+        let uniform1: Vec<_> = (0..1000)
+            .map(|i| (f64::from(i) * 0.618_034) % 1.0)
+            .collect();
+        let uniform2: Vec<_> = (0..1000)
+            .map(|i| (f64::from(i) * 0.414_214) % 1.0)
+            .collect();
+        let skewed: Vec<_> = uniform1.iter().map(|x| x * x).collect();
+
+        assert!(histograms_close(
+            &uniform1,
+            &uniform2,
+            10,
+            (0.0, 1.0),
+            0.05,
+            0.0
+        ));
+        assert!(!histograms_close(
+            &uniform1,
+            &skewed,
+            10,
+            (0.0, 1.0),
+            0.05,
+            0.0
+        ));
+        assert!(!histograms_close(
+            &uniform1,
+            &uniform2,
+            10,
+            (0.0, 0.5),
+            0.0,
+            0.0
+        ));
+        assert!(histograms_close(&uniform1, &[], 10, (2.0, 3.0), 0.0, 0.0));
+    }
+}
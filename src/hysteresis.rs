@@ -0,0 +1,117 @@
+use core::borrow::Borrow;
+
+use crate::{IsClose, Zero};
+
+/// Checks if a stream of values has settled, i.e. stayed close to a reference
+/// for a number of consecutive samples
+///
+/// This is useful for testing control loops or other time series, where
+/// values may pass close to the reference before they settle.
+///
+/// ```
+/// use isclose::HysteresisCloser;
+///
+/// let mut settled = HysteresisCloser::new(3, 0.0, 0.1);
+/// let samples = [0.0, 0.8, 1.05, 1.2, 0.97, 1.02, 0.99, 1.01];
+/// let results: Vec<_> = samples.iter().map(|x| settled.push(x, 1.0)).collect();
+///
+/// assert_eq!(results, [false, false, false, false, false, false, true, true]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HysteresisCloser<T> {
+    n: usize,
+    rel_tol: T,
+    abs_tol: T,
+    streak: usize,
+}
+
+impl<T> HysteresisCloser<T> {
+    /// Create a new checker requiring `n` consecutive samples within the
+    /// given relative and absolute tolerances
+    #[inline]
+    pub const fn new(n: usize, rel_tol: T, abs_tol: T) -> Self {
+        Self {
+            n,
+            rel_tol,
+            abs_tol,
+            streak: 0,
+        }
+    }
+
+    /// The number of consecutive samples that have been within tolerance
+    #[inline]
+    pub const fn streak(&self) -> usize {
+        self.streak
+    }
+
+    /// Check if the last `n` samples have been within tolerance
+    #[inline]
+    pub const fn is_settled(&self) -> bool {
+        self.streak >= self.n
+    }
+
+    /// Reset the streak, as if no samples had been pushed
+    #[inline]
+    pub fn reset(&mut self) {
+        self.streak = 0;
+    }
+}
+
+impl<T> HysteresisCloser<T>
+where
+    T: IsClose + Zero,
+{
+    /// Push a new sample and its reference value, returning whether the last
+    /// `n` samples have been within tolerance of their references
+    ///
+    /// A sample outside of tolerance resets the streak.
+    #[inline]
+    pub fn push(&mut self, value: impl Borrow<T>, reference: impl Borrow<T>) -> bool {
+        if value
+            .borrow()
+            .is_close_tol(reference, &self.rel_tol, &self.abs_tol)
+        {
+            self.streak = self.streak.saturating_add(1);
+        } else {
+            self.streak = 0;
+        }
+        self.is_settled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hysteresis_push() {
+        let mut settled = HysteresisCloser::new(4, 1e-3, 0.0);
+
+        // Noisy samples briefly pass within tolerance before settling
+        let noisy = [5.0, 12.0, 10.001, 9.5, 10.0, 10.0005, 10.2];
+        for x in noisy {
+            assert!(!settled.push(x, 10.0_f64));
+        }
+        assert_eq!(settled.streak(), 0);
+
+        let settling = [10.002, 9.999, 10.001, 10.0];
+        for (i, x) in settling.iter().enumerate() {
+            assert_eq!(settled.push(x, 10.0), i == 3);
+        }
+        assert!(settled.is_settled());
+        assert!(settled.push(10.0, 10.0));
+
+        assert!(!settled.push(10.5, 10.0));
+        assert_eq!(settled.streak(), 0);
+    }
+
+    #[test]
+    fn hysteresis_reset() {
+        let mut settled = HysteresisCloser::new(1, 0.0, 1e-6_f32);
+
+        assert!(settled.push(1.0, 1.0));
+        settled.reset();
+        assert!(!settled.is_settled());
+        assert_eq!(settled.streak(), 0);
+    }
+}
@@ -0,0 +1,62 @@
+use core::borrow::Borrow;
+
+use indexmap::IndexMap;
+
+use crate::{IsClose, Zero};
+
+/// Compares the maps in order, requiring the keys to be equal and the values
+/// to be close
+///
+/// Unlike [`IndexMap`]'s [`PartialEq`] implementation this comparison is
+/// sensitive to the order of the entries. To ignore the order, sort both maps
+/// with [`IndexMap::sort_keys`] before comparing them.
+impl<K, V, S, Tolerance> IsClose<Tolerance> for IndexMap<K, V, S>
+where
+    K: PartialEq,
+    V: IsClose<Tolerance>,
+    Tolerance: Zero,
+{
+    const ABS_TOL: Tolerance = V::ABS_TOL;
+    const REL_TOL: Tolerance = V::REL_TOL;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+    ) -> bool {
+        let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|((lhs_key, lhs), (rhs_key, rhs))| {
+                    lhs_key == rhs_key && lhs.is_close_tol(rhs, rel_tol, abs_tol)
+                })
+    }
+}
+
+// `IndexMap::from` requires indexmap's `std` feature
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::assert_is_close;
+
+    #[test]
+    fn index_map_is_close_tol() {
+        let map1 = IndexMap::from([("a", 0.1 + 0.2), ("b", 0.2 + 0.4)]);
+        let map2 = IndexMap::from([("a", 0.3), ("b", 0.6)]);
+        let map3 = IndexMap::from([("b", 0.6), ("a", 0.3)]);
+
+        assert_is_close!(map1, map2);
+        assert!(!map1.is_close(&map3));
+        assert!(!map1.is_close(IndexMap::from([("a", 0.3)])));
+        assert!(!map1.is_close(IndexMap::from([("a", 0.3), ("b", 0.7)])));
+        assert!(!map1.is_close(IndexMap::from([("a", 0.3), ("c", 0.6)])));
+
+        let mut map3 = map3;
+        map3.sort_keys();
+        assert_is_close!(map1, map3);
+    }
+}
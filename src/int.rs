@@ -0,0 +1,39 @@
+/// Check if two integers are approximately equal using the given absolute
+/// tolerance, in a const context
+///
+/// This allows checking constant values at compile time. Integer types can be
+/// converted to the argument types using `as`.
+///
+/// ```
+/// use isclose::is_close_const_int;
+///
+/// const TABLE: [u8; 3] = [10, 20, 31];
+/// const _: () = assert!(is_close_const_int(TABLE[2] as i128, 30, 1));
+/// ```
+///
+/// ```compile_fail
+/// use isclose::is_close_const_int;
+///
+/// const TABLE: [u8; 3] = [10, 20, 35];
+/// const _: () = assert!(is_close_const_int(TABLE[2] as i128, 30, 1));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_close_const_int(a: i128, b: i128, abs_tol: u128) -> bool {
+    a.abs_diff(b) <= abs_tol
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_int_is_close() {
+        const _: () = assert!(is_close_const_int(100, 101, 1));
+        const _: () = assert!(!is_close_const_int(100, 102, 1));
+
+        assert!(is_close_const_int(i128::MIN, i128::MIN + 1, 1));
+        assert!(is_close_const_int(i128::MIN, i128::MAX, u128::MAX));
+        assert!(!is_close_const_int(-1, 1, 1));
+    }
+}
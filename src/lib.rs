@@ -6,7 +6,32 @@
 //! Additional implementations are also hidden behind the following features:
 //!
 //! - `half` implements [`IsClose`] for [`f16`][::half::f16] and [`bf16`][::half::bf16]
-//! - `euclid` implements [`IsClose`] for [euclid]'s geometric types
+//! - `euclid` implements [`IsClose`] for [euclid][::euclid]'s geometric types
+//! - `palette` implements [`IsClose`] for [palette][::palette]'s color types
+//! - `chrono` implements [`IsClose`] for [`TimeDelta`][::chrono::TimeDelta]
+//! - `fixed` implements [`IsClose`] for [fixed][::fixed]'s fixed-point types
+//! - `num-traits` adds [`NumFloat`], which implements [`IsClose`] for any
+//!   [`num_traits::Float`]
+//! - `indexmap` implements [`IsClose`] for [`IndexMap`][::indexmap::IndexMap]
+//! - `defmt` adds [`defmt_assert_is_close!`], which logs failures using [`defmt`]
+//! - `env-tol` allows overriding the default tolerances used by
+//!   [`assert_is_close!`] using the `ISCLOSE_REL_TOL` and `ISCLOSE_ABS_TOL`
+//!   environment variables
+//! - `json-report` prints the details of failed assertions to stderr as JSON,
+//!   for use by CI systems
+//! - `global-config` adds [`set_default_tolerances`], which overrides the
+//!   default tolerances used by [`assert_is_close!`] for the whole program.
+//!   Both `env-tol` and `global-config` only override float tolerances, and
+//!   require a target with 64-bit atomics
+//! - `serde` adds [`assert_close_to_golden`], which compares values against
+//!   golden files deserialized from JSON using [`serde`]
+//! - `rayon` adds [`par_all_close`], which compares large slices in parallel
+//!   using [`rayon`]
+//! - `proptest` implements [`Arbitrary`][::proptest::arbitrary::Arbitrary] for
+//!   [`Tolerance`], generating valid tolerances for property testing
+//!   [`IsClose`] implementations using [`proptest`][::proptest]
+//! - `alloc` adds [`SliceIsClose::close_failures`] and [`histograms_close`]
+//!   without requiring `std`
 //!
 //! ## Usage:
 //!
@@ -75,6 +100,9 @@
 )]
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[doc(hidden)]
 pub mod macros;
 
@@ -82,17 +110,152 @@ pub mod macros;
 mod half;
 
 #[cfg(feature = "euclid")]
-mod euclid;
+pub mod euclid;
+
+#[cfg(feature = "palette")]
+pub mod palette;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+
+#[cfg(feature = "fixed")]
+mod fixed;
+
+#[cfg(feature = "num-traits")]
+mod num;
+
+#[cfg(feature = "indexmap")]
+mod indexmap;
+
+#[cfg(any(feature = "env-tol", feature = "global-config"))]
+mod config;
+
+#[cfg(all(
+    any(feature = "env-tol", feature = "global-config"),
+    not(target_has_atomic = "64")
+))]
+compile_error!("the `env-tol` and `global-config` features require 64-bit atomics");
+
+#[cfg(feature = "serde")]
+mod golden;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+
+#[cfg(feature = "proptest")]
+mod proptest;
+
+mod accumulator;
+mod cell;
+mod closeness;
+mod dynamic;
+#[cfg(feature = "std")]
+mod error;
+mod float;
+mod frobenius;
+#[cfg(feature = "alloc")]
+mod histogram;
+mod hysteresis;
+mod int;
+mod mahalanobis;
+mod option;
+mod report;
+mod round_trip;
+mod slice;
+mod tolerance;
+mod tuple;
+mod zero;
 
 use core::borrow::Borrow;
 
+pub use accumulator::Accumulator;
+pub use closeness::{closeness, Closeness};
+#[cfg(feature = "global-config")]
+pub use config::set_default_tolerances;
+pub use dynamic::DynIsClose;
+#[cfg(feature = "std")]
+pub use error::CloseError;
+pub use float::{FloatIsClose, TolKind};
+pub use frobenius::FrobeniusIsClose;
+#[cfg(feature = "serde")]
+pub use golden::assert_close_to_golden;
+#[cfg(feature = "alloc")]
+pub use histogram::histograms_close;
+pub use hysteresis::HysteresisCloser;
+pub use int::is_close_const_int;
+pub use mahalanobis::MahalanobisIsClose;
+#[cfg(feature = "num-traits")]
+pub use num::NumFloat;
+#[cfg(feature = "rayon")]
+pub use parallel::par_all_close;
+pub use report::CloseReport;
+pub use round_trip::round_trip_is_close;
+pub use slice::SliceIsClose;
+pub use tolerance::{all_of, any_of, CompositeTolerance, Tolerance};
+pub use zero::{HasZero, IsCloseToZero};
+
 /// Utility crate since floats don't implement [`f32::abs`] in `no_std`
 trait Abs {
     fn abs(&self) -> Self;
 }
 
+/// Utility crate since floats don't implement [`f32::sqrt`] in `no_std`
+trait Sqrt {
+    fn sqrt(&self) -> Self;
+}
+
+/// Utility crate since floats don't implement [`f32::log10`] in `no_std`
+trait Log10 {
+    fn log10(&self) -> Self;
+}
+
+/// Utility trait for checking if a difference is within a tolerance
+///
+/// This is used instead of [`PartialOrd`] so that scalar types which don't
+/// implement it, or whose ordering isn't suitable, can still be compared.
+trait TolOrd {
+    /// Check if the difference `self` is within the tolerance `tol`
+    fn is_within(&self, tol: &Self) -> bool;
+}
+
+impl TolOrd for f32 {
+    #[inline]
+    fn is_within(&self, tol: &Self) -> bool {
+        self <= tol
+    }
+}
+
+impl TolOrd for f64 {
+    #[inline]
+    fn is_within(&self, tol: &Self) -> bool {
+        self <= tol
+    }
+}
+
+/// Compares two scalar values, scaling the relative tolerance by the larger of
+/// their magnitudes
+#[inline]
+fn is_close_scalar<T>(lhs: &T, rhs: &T, rel_tol: &T, abs_tol: &T) -> bool
+where
+    T: Abs
+        + TolOrd
+        + Copy
+        + core::ops::Add<Output = T>
+        + core::ops::Sub<Output = T>
+        + core::ops::Mul<Output = T>,
+{
+    let (abs_lhs, abs_rhs) = (Abs::abs(lhs), Abs::abs(rhs));
+    let scale = if abs_lhs.is_within(&abs_rhs) {
+        abs_rhs
+    } else {
+        abs_lhs
+    };
+    let tol = scale * *rel_tol + *abs_tol;
+    Abs::abs(&(*lhs - *rhs)).is_within(&tol)
+}
+
 #[cfg(feature = "std")]
-mod abs {
+mod ops {
     impl crate::Abs for f32 {
         fn abs(&self) -> Self {
             Self::abs(*self)
@@ -104,10 +267,34 @@ mod abs {
             Self::abs(*self)
         }
     }
+
+    impl crate::Sqrt for f32 {
+        fn sqrt(&self) -> Self {
+            Self::sqrt(*self)
+        }
+    }
+
+    impl crate::Sqrt for f64 {
+        fn sqrt(&self) -> Self {
+            Self::sqrt(*self)
+        }
+    }
+
+    impl crate::Log10 for f32 {
+        fn log10(&self) -> Self {
+            Self::log10(*self)
+        }
+    }
+
+    impl crate::Log10 for f64 {
+        fn log10(&self) -> Self {
+            Self::log10(*self)
+        }
+    }
 }
 
 #[cfg(all(not(feature = "std"), feature = "libm"))]
-mod abs {
+mod ops {
     impl crate::Abs for f32 {
         fn abs(&self) -> Self {
             libm::fabsf(*self)
@@ -119,12 +306,55 @@ mod abs {
             libm::fabs(*self)
         }
     }
+
+    impl crate::Sqrt for f32 {
+        fn sqrt(&self) -> Self {
+            libm::sqrtf(*self)
+        }
+    }
+
+    impl crate::Sqrt for f64 {
+        fn sqrt(&self) -> Self {
+            libm::sqrt(*self)
+        }
+    }
+
+    impl crate::Log10 for f32 {
+        fn log10(&self) -> Self {
+            libm::log10f(*self)
+        }
+    }
+
+    impl crate::Log10 for f64 {
+        fn log10(&self) -> Self {
+            libm::log10(*self)
+        }
+    }
 }
 
 /// Trait used to return a generic zero value for the tolerance
+///
+/// This is implemented for tuples of tolerances, so types with fields of
+/// different precisions can implement e.g. `IsClose<(f32, f64)>` to use
+/// separate tolerances, and separate default tolerances, for each field.
 pub trait Zero {
     /// The zero constant
     const ZERO: Self;
+
+    /// Convert a default tolerance override, as set by the `env-tol` and
+    /// `global-config` features, to this tolerance type
+    ///
+    /// Overrides are ignored for types which return [`None`], which is the
+    /// default.
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    fn from_tol_override(_tol: f64) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 /// Trait used for testing if floating point values are approximately equal
@@ -177,6 +407,12 @@ where
 
 impl Zero for f32 {
     const ZERO: Self = 0.0;
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)] // tolerances only need f32 precision
+    fn from_tol_override(tol: f64) -> Option<Self> {
+        Some(tol as Self)
+    }
 }
 
 impl IsClose for f32 {
@@ -190,14 +426,17 @@ impl IsClose for f32 {
         rel_tol: impl Borrow<Self>,
         abs_tol: impl Borrow<Self>,
     ) -> bool {
-        let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        let tol = Self::max(Abs::abs(self), Abs::abs(other)) * rel_tol + abs_tol;
-        (*self - *other).abs() <= tol
+        is_close_scalar(self, other.borrow(), rel_tol.borrow(), abs_tol.borrow())
     }
 }
 
 impl Zero for f64 {
     const ZERO: Self = 0.0;
+
+    #[inline]
+    fn from_tol_override(tol: f64) -> Option<Self> {
+        Some(tol)
+    }
 }
 
 impl IsClose for f64 {
@@ -211,9 +450,30 @@ impl IsClose for f64 {
         rel_tol: impl Borrow<Self>,
         abs_tol: impl Borrow<Self>,
     ) -> bool {
-        let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
-        let tol = Self::max(Abs::abs(self), Abs::abs(other)) * rel_tol + abs_tol;
-        (*self - *other).abs() <= tol
+        is_close_scalar(self, other.borrow(), rel_tol.borrow(), abs_tol.borrow())
+    }
+}
+
+/// Compares the referenced values
+///
+/// This allows comparing e.g. `Option<&f64>`s returned by
+/// [`HashMap::get`][std::collections::HashMap::get].
+impl<T, Tolerance> IsClose<Tolerance> for &T
+where
+    T: IsClose<Tolerance> + ?Sized,
+    Tolerance: Zero,
+{
+    const ABS_TOL: Tolerance = T::ABS_TOL;
+    const REL_TOL: Tolerance = T::REL_TOL;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+    ) -> bool {
+        (**self).is_close_tol(*other.borrow(), rel_tol, abs_tol)
     }
 }
 
@@ -261,4 +521,76 @@ mod tests {
         assert!(PI_F64.is_close_tol(22.0 / 7.0, 1e-2, 1e-2));
         assert!(!PI_F64.is_close_tol(22.0 / 7.0, 1e-5, 1e-5));
     }
+
+    /// A fixed-point scalar with three decimal places, standing in for
+    /// user-defined scalar types
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Milli(i64);
+
+    impl Abs for Milli {
+        fn abs(&self) -> Self {
+            Self(self.0.abs())
+        }
+    }
+
+    impl TolOrd for Milli {
+        fn is_within(&self, tol: &Self) -> bool {
+            self.0 <= tol.0
+        }
+    }
+
+    impl core::ops::Add for Milli {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            Self(self.0 + rhs.0)
+        }
+    }
+
+    impl core::ops::Sub for Milli {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            Self(self.0 - rhs.0)
+        }
+    }
+
+    impl core::ops::Mul for Milli {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            Self(self.0 * rhs.0 / 1000)
+        }
+    }
+
+    impl Zero for Milli {
+        const ZERO: Self = Self(0);
+    }
+
+    impl IsClose for Milli {
+        const ABS_TOL: Self = Self(1);
+        const REL_TOL: Self = Self(1);
+
+        fn is_close_tol(
+            &self,
+            other: impl Borrow<Self>,
+            rel_tol: impl Borrow<Self>,
+            abs_tol: impl Borrow<Self>,
+        ) -> bool {
+            is_close_scalar(self, other.borrow(), rel_tol.borrow(), abs_tol.borrow())
+        }
+    }
+
+    #[test]
+    fn custom_is_close_tol() {
+        assert!(Milli(1000).is_close(Milli(1001)));
+        assert!(Milli(1000).is_close(Milli(1002)));
+        assert!(!Milli(1000).is_close(Milli(1003)));
+        assert!(Milli(-1000).is_close(Milli(-1001)));
+
+        assert!(Milli(100_000).is_close_rel_tol(Milli(101_000), Milli(10)));
+        assert!(!Milli(100_000).is_close_rel_tol(Milli(102_000), Milli(10)));
+        assert!(Milli(0).is_close_abs_tol(Milli(-5), Milli(5)));
+        assert!(!Milli(0).is_close_abs_tol(Milli(6), Milli(5)));
+    }
 }
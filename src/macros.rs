@@ -1,8 +1,13 @@
 use crate::{IsClose, Zero};
-use core::fmt::Debug;
+use core::fmt::{Debug, Display};
 
 /// Utility function to print the panicking error message
+///
+/// This only resolves the default tolerances, then erases the types before
+/// calling [`assert_failed_inner`], so the formatting machinery is only
+/// compiled once rather than for every type compared.
 #[doc(hidden)]
+#[cold]
 #[track_caller]
 pub fn assert_failed<Value, Tolerance>(
     lhs: &Value,
@@ -12,7 +17,7 @@ pub fn assert_failed<Value, Tolerance>(
     args: Option<core::fmt::Arguments<'_>>,
 ) -> !
 where
-    Value: IsClose<Tolerance> + Debug,
+    Value: IsClose<Tolerance> + Debug + ?Sized,
     Tolerance: Zero + Debug,
 {
     let zero = Tolerance::ZERO;
@@ -24,6 +29,23 @@ where
         (None, None) => (&def_rel, &def_abs),
     };
 
+    assert_failed_inner(&lhs, &rhs, rel_tol, abs_tol, args)
+}
+
+/// Non-generic implementation of [`assert_failed`]
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn assert_failed_inner(
+    lhs: &dyn Debug,
+    rhs: &dyn Debug,
+    rel_tol: &dyn Debug,
+    abs_tol: &dyn Debug,
+    args: Option<core::fmt::Arguments<'_>>,
+) -> ! {
+    #[cfg(feature = "json-report")]
+    eprintln!("{}", json_report(lhs, rhs, rel_tol, abs_tol));
+
     #[allow(clippy::option_if_let_else)] // map_or_else is super ugly here
     match args {
         Some(args) => panic!(
@@ -45,7 +67,577 @@ where
     }
 }
 
+/// Utility function checking if a relative error in decibels is within the
+/// given maximum
+///
+/// This returns `false` if either is NaN.
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub fn is_within_db(error_db: f64, max_db: f64) -> bool {
+    error_db <= max_db
+}
+
+/// Utility function to print the panicking error message for a comparison in
+/// decibels
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn assert_failed_db(
+    lhs: &dyn Debug,
+    rhs: &dyn Debug,
+    error_db: f64,
+    max_db: f64,
+    args: Option<core::fmt::Arguments<'_>>,
+) -> ! {
+    #[cfg(feature = "json-report")]
+    eprintln!("{}", json_report_db(lhs, rhs, error_db, max_db));
+
+    #[allow(clippy::option_if_let_else)] // map_or_else is super ugly here
+    match args {
+        Some(args) => panic!(
+            "assertion `left ~= right` failed: {}
+    left: {:?}
+   right: {:?}
+   error: {:?} dB
+ max err: {:?} dB",
+            args, lhs, rhs, error_db, max_db,
+        ),
+        None => panic!(
+            "assertion `left ~= right` failed
+    left: {:?}
+   right: {:?}
+   error: {:?} dB
+ max err: {:?} dB",
+            lhs, rhs, error_db, max_db,
+        ),
+    }
+}
+
+/// Utility function to print the panicking error message for a comparison with
+/// a magnitude threshold between the absolute and relative tolerances
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn assert_failed_piecewise(
+    lhs: &dyn Debug,
+    rhs: &dyn Debug,
+    threshold: &dyn Debug,
+    abs_tol: &dyn Debug,
+    rel_tol: &dyn Debug,
+    args: Option<core::fmt::Arguments<'_>>,
+) -> ! {
+    #[cfg(feature = "json-report")]
+    eprintln!("{}", json_report(lhs, rhs, rel_tol, abs_tol));
+
+    #[allow(clippy::option_if_let_else)] // map_or_else is super ugly here
+    match args {
+        Some(args) => panic!(
+            "assertion `left ~= right` failed: {}
+    left: {:?}
+   right: {:?}
+ abs tol: {:?} below {:?}
+ rel tol: {:?} above {:?}",
+            args, lhs, rhs, abs_tol, threshold, rel_tol, threshold,
+        ),
+        None => panic!(
+            "assertion `left ~= right` failed
+    left: {:?}
+   right: {:?}
+ abs tol: {:?} below {:?}
+ rel tol: {:?} above {:?}",
+            lhs, rhs, abs_tol, threshold, rel_tol, threshold,
+        ),
+    }
+}
+
+/// Formats the details of a failed comparison as a JSON object
+///
+/// The values and tolerances are formatted using [`Debug`]. The absolute
+/// difference is only included if both values format as finite floats, and is
+/// `null` otherwise.
+#[cfg(feature = "json-report")]
+fn json_report(
+    lhs: &dyn Debug,
+    rhs: &dyn Debug,
+    rel_tol: &dyn Debug,
+    abs_tol: &dyn Debug,
+) -> String {
+    let (lhs, rhs) = (format!("{lhs:?}"), format!("{rhs:?}"));
+
+    format!(
+        r#"{{"left":{},"right":{},"rel_tol":{},"abs_tol":{},"abs_diff":{}}}"#,
+        json_string(&lhs),
+        json_string(&rhs),
+        json_string(&format!("{rel_tol:?}")),
+        json_string(&format!("{abs_tol:?}")),
+        json_abs_diff(&lhs, &rhs),
+    )
+}
+
+/// Formats the details of a failed comparison in decibels as a JSON object
+///
+/// This is equivalent to [`json_report`], except the tolerances are replaced
+/// by the relative error and maximum error in decibels, which are `null` if
+/// they aren't finite.
+#[cfg(feature = "json-report")]
+fn json_report_db(lhs: &dyn Debug, rhs: &dyn Debug, error_db: f64, max_db: f64) -> String {
+    let (lhs, rhs) = (format!("{lhs:?}"), format!("{rhs:?}"));
+
+    format!(
+        r#"{{"left":{},"right":{},"error_db":{},"max_db":{},"abs_diff":{}}}"#,
+        json_string(&lhs),
+        json_string(&rhs),
+        json_number(error_db),
+        json_number(max_db),
+        json_abs_diff(&lhs, &rhs),
+    )
+}
+
+/// Formats a string as a JSON string literal
+#[cfg(feature = "json-report")]
+fn json_string(string: &str) -> String {
+    use core::fmt::Write;
+
+    let mut result = String::with_capacity(string.len() + 2);
+    result.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            // Writing to a String can't fail
+            c if c.is_control() => drop(write!(result, "\\u{:04x}", u32::from(c))),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// Formats a float as a JSON number, or `null` if it isn't finite
+#[cfg(feature = "json-report")]
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        format!("{value}")
+    } else {
+        "null".into()
+    }
+}
+
+/// Formats the absolute difference between two values formatted as floats as
+/// a JSON number, or `null` if either isn't a float
+#[cfg(feature = "json-report")]
+fn json_abs_diff(lhs: &str, rhs: &str) -> String {
+    match (lhs.parse::<f64>(), rhs.parse::<f64>()) {
+        (Ok(lhs), Ok(rhs)) => json_number((lhs - rhs).abs()),
+        _ => "null".into(),
+    }
+}
+
+/// Utility function to print the panicking error message using [`Display`]
+/// rather than [`Debug`]
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_failed_display<Value, Tolerance>(
+    lhs: &Value,
+    rhs: &Value,
+    rel_tol: &Tolerance,
+    abs_tol: &Tolerance,
+    args: Option<core::fmt::Arguments<'_>>,
+) -> !
+where
+    Value: IsClose<Tolerance> + Display + ?Sized,
+    Tolerance: Zero + Display,
+{
+    #[allow(clippy::option_if_let_else)] // map_or_else is super ugly here
+    match args {
+        Some(args) => panic!(
+            "assertion `left ~= right` failed: {}
+    left: {}
+   right: {}
+ rel tol: {}
+ abs tol: {}",
+            args, lhs, rhs, rel_tol, abs_tol,
+        ),
+        None => panic!(
+            "assertion `left ~= right` failed
+    left: {}
+   right: {}
+ rel tol: {}
+ abs tol: {}",
+            lhs, rhs, rel_tol, abs_tol,
+        ),
+    }
+}
+
+/// Utility function to mark the result of [`is_close!`] as must use
+#[doc(hidden)]
+#[inline]
+#[must_use = "the result of `is_close!` should be used"]
+pub const fn must_use(result: bool) -> bool {
+    result
+}
+
+/// Utility function comparing values using an absolute tolerance below the
+/// given magnitude threshold, and a relative tolerance above it
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub fn is_close_piecewise<T>(lhs: &T, rhs: &T, threshold: &T, abs_tol: &T, rel_tol: &T) -> bool
+where
+    T: IsClose + Zero + PartialOrd + Copy + core::ops::Sub<Output = T>,
+{
+    let abs = |x: T| if x < T::ZERO { T::ZERO - x } else { x };
+    let magnitude = if abs(*lhs) < abs(*rhs) {
+        abs(*rhs)
+    } else {
+        abs(*lhs)
+    };
+
+    if magnitude < *threshold {
+        lhs.is_close_abs_tol(rhs, abs_tol)
+    } else {
+        lhs.is_close_rel_tol(rhs, rel_tol)
+    }
+}
+
+/// Utility function converting a tolerance given as a percentage of the
+/// reference value into an absolute tolerance
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub fn pct_of_tol<T>(reference: &T, pct: &T) -> T
+where
+    T: Zero
+        + PartialOrd
+        + Copy
+        + From<u8>
+        + core::ops::Sub<Output = T>
+        + core::ops::Mul<Output = T>
+        + core::ops::Div<Output = T>,
+{
+    let magnitude = if *reference < T::ZERO {
+        T::ZERO - *reference
+    } else {
+        *reference
+    };
+    magnitude * *pct / T::from(100)
+}
+
+/// Utility function returning the default relative and absolute tolerances
+/// used by the macros and [`Closeness`][crate::Closeness]
+///
+/// For [`f32`] and [`f64`] tolerances, these are overridden by the tolerances
+/// set using [`set_default_tolerances`][crate::set_default_tolerances] and
+/// then by the `ISCLOSE_REL_TOL` and `ISCLOSE_ABS_TOL` environment variables,
+/// depending on the enabled features.
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub fn default_tol<Value, Tolerance>(_: &Value) -> (Tolerance, Tolerance)
+where
+    Value: IsClose<Tolerance> + ?Sized,
+    Tolerance: Zero,
+{
+    #[cfg(any(feature = "env-tol", feature = "global-config"))]
+    {
+        #[cfg(feature = "global-config")]
+        let (rel_tol, abs_tol) = crate::config::tol_overrides();
+        #[cfg(not(feature = "global-config"))]
+        let (rel_tol, abs_tol) = (None, None);
+
+        #[cfg(feature = "env-tol")]
+        let (rel_tol, abs_tol) = {
+            let (env_rel, env_abs) = crate::config::env_tol_overrides();
+            (env_rel.or(rel_tol), env_abs.or(abs_tol))
+        };
+
+        (
+            rel_tol
+                .and_then(Tolerance::from_tol_override)
+                .unwrap_or(Value::REL_TOL),
+            abs_tol
+                .and_then(Tolerance::from_tol_override)
+                .unwrap_or(Value::ABS_TOL),
+        )
+    }
+
+    #[cfg(not(any(feature = "env-tol", feature = "global-config")))]
+    (Value::REL_TOL, Value::ABS_TOL)
+}
+
+/// Utility struct collecting the failed comparisons of
+/// [`assert_close_group!`]
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct GroupFailures {
+    total: usize,
+    failures: Vec<String>,
+}
+
+#[cfg(feature = "std")]
+impl GroupFailures {
+    /// Record the result of a comparison, formatting its details if it failed
+    pub fn record<Value, Tolerance>(
+        &mut self,
+        passed: bool,
+        expr: &str,
+        lhs: &Value,
+        rhs: &Value,
+        rel_tol: &Tolerance,
+        abs_tol: &Tolerance,
+    ) where
+        Value: Debug + ?Sized,
+        Tolerance: Debug,
+    {
+        self.total += 1;
+        if passed {
+            return;
+        }
+
+        #[cfg(feature = "json-report")]
+        eprintln!("{}", json_report(&lhs, &rhs, rel_tol, abs_tol));
+
+        self.failures.push(format!(
+            " [{}] {}
+    left: {:?}
+   right: {:?}
+ rel tol: {:?}
+ abs tol: {:?}",
+            self.total, expr, lhs, rhs, rel_tol, abs_tol,
+        ));
+    }
+
+    /// Panic listing all failed comparisons, if there were any
+    #[track_caller]
+    pub fn finish(self) {
+        assert!(
+            self.failures.is_empty(),
+            "assertion `left ~= right` failed for {} of {} comparisons\n{}",
+            self.failures.len(),
+            self.total,
+            self.failures.join("\n"),
+        );
+    }
+}
+
+/// Utility struct collecting the differing fields of
+/// [`assert_fields_close!`]
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct FieldFailures {
+    failures: Vec<String>,
+}
+
+#[cfg(feature = "std")]
+impl FieldFailures {
+    /// Compare a field exactly, formatting its details if it differs
+    pub fn exact<Value>(&mut self, name: &str, lhs: &Value, rhs: &Value)
+    where
+        Value: PartialEq + Debug + ?Sized,
+    {
+        if lhs != rhs {
+            self.failures.push(format!(
+                " `{name}` differs exactly
+    left: {lhs:?}
+   right: {rhs:?}",
+            ));
+        }
+    }
+
+    /// Record the result of an approximate comparison of a field, formatting
+    /// its details if it failed
+    pub fn close<Value, Tolerance>(
+        &mut self,
+        passed: bool,
+        name: &str,
+        lhs: &Value,
+        rhs: &Value,
+        rel_tol: &Tolerance,
+        abs_tol: &Tolerance,
+    ) where
+        Value: Debug + ?Sized,
+        Tolerance: Debug,
+    {
+        if !passed {
+            self.failures.push(format!(
+                " `{name}` differs approximately
+    left: {lhs:?}
+   right: {rhs:?}
+ rel tol: {rel_tol:?}
+ abs tol: {abs_tol:?}",
+            ));
+        }
+    }
+
+    /// Panic listing all differing fields, if there were any
+    #[track_caller]
+    pub fn finish(self) {
+        assert!(
+            self.failures.is_empty(),
+            "assertion `left ~= right` failed for fields\n{}",
+            self.failures.join("\n"),
+        );
+    }
+}
+
+/// Utility function asserting that a sequence converges to the target, as
+/// used by [`assert_converges_close!`]
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_converges<T>(sequence: &[T], target: &T, rel_tol: &T, abs_tol: &T, monotonic: bool)
+where
+    T: IsClose + Zero + Debug + Copy + PartialOrd + core::ops::Sub<Output = T>,
+{
+    assert!(!sequence.is_empty(), "assertion failed: sequence is empty");
+    let last = &sequence[sequence.len() - 1];
+
+    if monotonic {
+        let step = |(prev, next): (&T, &T)| {
+            let diff = *next - *prev;
+            if diff < T::ZERO {
+                T::ZERO - diff
+            } else {
+                diff
+            }
+        };
+        let mut steps = sequence.iter().zip(&sequence[1..]).map(step).enumerate();
+        if let Some((_, mut prev)) = steps.next() {
+            for (i, step) in steps {
+                assert!(
+                    step <= prev,
+                    "assertion failed: sequence differences are not non-increasing
+    step {}: {:?}
+    step {}: {:?}",
+                    i - 1,
+                    prev,
+                    i,
+                    step,
+                );
+                prev = step;
+            }
+        }
+    }
+
+    if !last.is_close_tol(target, rel_tol, abs_tol) {
+        assert_failed(
+            last,
+            target,
+            Some(rel_tol),
+            Some(abs_tol),
+            Some(format_args!("sequence does not converge to target")),
+        );
+    }
+}
+
+/// Utility function asserting that a value round trips through an encoding,
+/// as used by [`assert_round_trips_close!`]
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_round_trips<T, U, Tolerance>(
+    value: &T,
+    encode: impl Fn(&T) -> U,
+    decode: impl Fn(&U) -> T,
+    rel_tol: &Tolerance,
+    abs_tol: &Tolerance,
+) where
+    T: IsClose<Tolerance> + Debug,
+    Tolerance: Zero + Debug,
+{
+    let decoded = decode(&encode(value));
+    if !value.is_close_tol(&decoded, rel_tol, abs_tol) {
+        assert_failed(
+            value,
+            &decoded,
+            Some(rel_tol),
+            Some(abs_tol),
+            Some(format_args!("value does not round trip")),
+        );
+    }
+}
+
+/// Utility function to create the error returned by [`try_assert_is_close!`]
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[must_use]
+pub fn close_error<Value, Tolerance>(
+    lhs: &Value,
+    rhs: &Value,
+    rel_tol: &Tolerance,
+    abs_tol: &Tolerance,
+    args: Option<core::fmt::Arguments<'_>>,
+) -> crate::CloseError
+where
+    Value: IsClose<Tolerance> + Debug + ?Sized,
+    Tolerance: Zero + Debug,
+{
+    crate::CloseError {
+        left: format!("{lhs:?}"),
+        right: format!("{rhs:?}"),
+        rel_tol: format!("{rel_tol:?}"),
+        abs_tol: format!("{abs_tol:?}"),
+        message: args.map(|args| args.to_string()),
+    }
+}
+
+/// Utility function to log the operands using [`defmt`] before panicking
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[track_caller]
+pub fn defmt_assert_failed<Value, Tolerance>(
+    lhs: &Value,
+    rhs: &Value,
+    rel_tol: &Tolerance,
+    abs_tol: &Tolerance,
+) -> !
+where
+    Value: IsClose<Tolerance> + defmt::Format + ?Sized,
+    Tolerance: Zero + defmt::Format,
+{
+    defmt::error!(
+        "assertion `left ~= right` failed\n    left: {}\n   right: {}\n rel tol: {}\n abs tol: {}",
+        lhs,
+        rhs,
+        rel_tol,
+        abs_tol,
+    );
+    panic!("assertion `left ~= right` failed")
+}
+
 /// Assert that two values are approximately equal
+///
+/// The tolerances default to those of the values' [`IsClose`] implementation,
+/// or can be given by a named [`Tolerance`][crate::Tolerance] declared using
+/// [`tol!`][crate::tol], e.g. `assert_is_close!(a, b, named = COARSE)`.
+///
+/// For values spanning many orders of magnitude, an absolute tolerance can be
+/// used below a magnitude threshold and a relative tolerance above it, e.g.
+/// `assert_is_close!(a, b, abs_tol_below = (1.0, 1e-9), rel_tol_above = 1e-6)`.
+///
+/// To allow an error of a percentage of a specific reference value, rather
+/// than of the larger of the two values as with a relative tolerance, use e.g.
+/// `assert_is_close!(measured, expected, pct_of = expected, tol = 2.0)`. This
+/// is equivalent to an absolute tolerance of 2% of the magnitude of
+/// `expected`.
+///
+/// For floats, the relative error of the first value compared to the second
+/// can also be limited in decibels, e.g. `assert_is_close!(a, b, db = -60.0)`
+/// asserts the error is at most -60 dB, i.e. a relative error of `0.001`. See
+/// [`FloatIsClose::rel_error_db`][crate::FloatIsClose::rel_error_db].
+///
+/// With the `env-tol` feature enabled, the default [`f32`] and [`f64`]
+/// tolerances can be overridden at runtime using the `ISCLOSE_REL_TOL` and
+/// `ISCLOSE_ABS_TOL` environment variables, which are read once on first use.
+/// Invalid values are ignored with a warning. Similarly with the
+/// `global-config` feature enabled, the default tolerances can be overridden
+/// using `set_default_tolerances`.
+///
+/// On failure this panics at the location of the macro invocation, or at the
+/// location of the caller if invoked from within a `#[track_caller]` function.
 #[macro_export]
 macro_rules! assert_is_close {
     ($lhs:expr, $rhs:expr $(,)?) => {
@@ -53,9 +645,131 @@ macro_rules! assert_is_close {
             (lhs, rhs) => {
                 use core::borrow::Borrow;
                 let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
-                if !$crate::IsClose::is_close(lhs, rhs) {
+                let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                if !$crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol) {
+                    $crate::macros::assert_failed(
+                        lhs, rhs, Some(&rel_tol), Some(&abs_tol), None,
+                    );
+                }
+            }
+        }
+    };
+
+    ($lhs:expr, $rhs:expr, named = $tol:expr $(,)?) => {
+        match (&$lhs, &$rhs, &$tol) {
+            (lhs, rhs, tol) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let tol: &$crate::Tolerance<_> = tol;
+                if !$crate::IsClose::is_close_tol(lhs, rhs, &tol.rel, &tol.abs) {
                     $crate::macros::assert_failed(
-                        lhs, rhs, None, None, None,
+                        lhs, rhs, Some(&tol.rel), Some(&tol.abs), None,
+                    );
+                }
+            }
+        }
+    };
+
+    ($lhs:expr, $rhs:expr, named = $tol:expr, $($arg:tt)+) => {
+        match (&$lhs, &$rhs, &$tol) {
+            (lhs, rhs, tol) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let tol: &$crate::Tolerance<_> = tol;
+                if !$crate::IsClose::is_close_tol(lhs, rhs, &tol.rel, &tol.abs) {
+                    $crate::macros::assert_failed(
+                        lhs, rhs, Some(&tol.rel), Some(&tol.abs), Some(core::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+
+    (
+        $lhs:expr, $rhs:expr,
+        abs_tol_below = ($threshold:expr, $abs_tol:expr), rel_tol_above = $rel_tol:expr $(,)?
+    ) => {
+        match (&$lhs, &$rhs, &$threshold, &$abs_tol, &$rel_tol) {
+            (lhs, rhs, threshold, abs_tol, rel_tol) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                if !$crate::macros::is_close_piecewise(lhs, rhs, threshold, abs_tol, rel_tol) {
+                    $crate::macros::assert_failed_piecewise(
+                        &lhs, &rhs, threshold, abs_tol, rel_tol, None,
+                    );
+                }
+            }
+        }
+    };
+
+    (
+        $lhs:expr, $rhs:expr,
+        abs_tol_below = ($threshold:expr, $abs_tol:expr), rel_tol_above = $rel_tol:expr,
+        $($arg:tt)+
+    ) => {
+        match (&$lhs, &$rhs, &$threshold, &$abs_tol, &$rel_tol) {
+            (lhs, rhs, threshold, abs_tol, rel_tol) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                if !$crate::macros::is_close_piecewise(lhs, rhs, threshold, abs_tol, rel_tol) {
+                    $crate::macros::assert_failed_piecewise(
+                        &lhs, &rhs, threshold, abs_tol, rel_tol, Some(core::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+
+    ($lhs:expr, $rhs:expr, db = $db:expr $(,)?) => {
+        match (&$lhs, &$rhs, &$db) {
+            (lhs, rhs, db) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let error_db = $crate::FloatIsClose::rel_error_db(lhs, rhs);
+                if !$crate::macros::is_within_db(error_db, *db) {
+                    $crate::macros::assert_failed_db(&lhs, &rhs, error_db, *db, None);
+                }
+            }
+        }
+    };
+
+    ($lhs:expr, $rhs:expr, db = $db:expr, $($arg:tt)+) => {
+        match (&$lhs, &$rhs, &$db) {
+            (lhs, rhs, db) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let error_db = $crate::FloatIsClose::rel_error_db(lhs, rhs);
+                if !$crate::macros::is_within_db(error_db, *db) {
+                    $crate::macros::assert_failed_db(
+                        &lhs, &rhs, error_db, *db, Some(core::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+
+    ($lhs:expr, $rhs:expr, pct_of = $reference:expr, tol = $pct:expr $(,)?) => {
+        match (&$lhs, &$rhs, &$reference, &$pct) {
+            (lhs, rhs, reference, pct) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let abs_tol = $crate::macros::pct_of_tol(reference, pct);
+                if !$crate::IsClose::is_close_abs_tol(lhs, rhs, &abs_tol) {
+                    $crate::macros::assert_failed(lhs, rhs, None, Some(&abs_tol), None);
+                }
+            }
+        }
+    };
+
+    ($lhs:expr, $rhs:expr, pct_of = $reference:expr, tol = $pct:expr, $($arg:tt)+) => {
+        match (&$lhs, &$rhs, &$reference, &$pct) {
+            (lhs, rhs, reference, pct) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let abs_tol = $crate::macros::pct_of_tol(reference, pct);
+                if !$crate::IsClose::is_close_abs_tol(lhs, rhs, &abs_tol) {
+                    $crate::macros::assert_failed(
+                        lhs, rhs, None, Some(&abs_tol), Some(core::format_args!($($arg)+)),
                     );
                 }
             }
@@ -67,9 +781,48 @@ macro_rules! assert_is_close {
             (lhs, rhs) => {
                 use core::borrow::Borrow;
                 let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
-                if !$crate::IsClose::is_close(lhs, rhs) {
+                let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                if !$crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol) {
                     $crate::macros::assert_failed(
-                        lhs, rhs, None, None, Some(core::format_args!($($arg)+))
+                        lhs, rhs, Some(&rel_tol), Some(&abs_tol), Some(core::format_args!($($arg)+))
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Assert that two values are approximately equal, formatting the values
+/// using [`Display`][core::fmt::Display] on failure
+///
+/// This is equivalent to [`assert_is_close!`] for types which don't implement
+/// [`Debug`][core::fmt::Debug].
+#[macro_export]
+macro_rules! assert_is_close_display {
+    ($lhs:expr, $rhs:expr $(,)?) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                if !$crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol) {
+                    $crate::macros::assert_failed_display(
+                        lhs, rhs, &rel_tol, &abs_tol, None,
+                    );
+                }
+            }
+        }
+    };
+
+    ($lhs:expr, $rhs:expr, $($arg:tt)+) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                if !$crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol) {
+                    $crate::macros::assert_failed_display(
+                        lhs, rhs, &rel_tol, &abs_tol, Some(core::format_args!($($arg)+)),
                     );
                 }
             }
@@ -77,6 +830,357 @@ macro_rules! assert_is_close {
     };
 }
 
+/// Assert that two values are approximately equal, logging the values using
+/// [`defmt`] on failure
+///
+/// This only requires the values to implement [`defmt::Format`] rather than
+/// [`Debug`][core::fmt::Debug], and the panic message itself contains no
+/// formatted values.
+#[cfg(feature = "defmt")]
+#[macro_export]
+macro_rules! defmt_assert_is_close {
+    ($lhs:expr, $rhs:expr $(,)?) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                if !$crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol) {
+                    $crate::macros::defmt_assert_failed(lhs, rhs, &rel_tol, &abs_tol);
+                }
+            }
+        }
+    };
+}
+
+/// Assert that two values are approximately equal, panicking with a fixed
+/// message on failure
+///
+/// This is a minimal alternative to [`assert_is_close!`] for size-constrained
+/// `no_std` targets. The panic message is a static string, so no formatting
+/// code is generated for the values or tolerances and they don't need to
+/// implement [`Debug`][core::fmt::Debug]. The tradeoff is that the message
+/// doesn't include the values or tolerances, so failures are harder to
+/// diagnose. The same default tolerances as [`assert_is_close!`] are always
+/// used.
+///
+/// ```should_panic
+/// #![no_std]
+/// # extern crate std; // for the panic handler
+/// use isclose::assert_is_close_min;
+///
+/// fn main() {
+///     assert_is_close_min!(0.1 + 0.2, 0.3);
+///     assert_is_close_min!(1.0, 1.1); // panics with "assertion `left ~= right` failed"
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_is_close_min {
+    ($lhs:expr, $rhs:expr $(,)?) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                if !$crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol) {
+                    core::panic!("assertion `left ~= right` failed");
+                }
+            }
+        }
+    };
+}
+
+/// Check that two values are approximately equal, returning a
+/// [`CloseError`][crate::CloseError] rather than panicking if they are not
+///
+/// This accepts the same arguments as the default form of
+/// [`assert_is_close!`], and evaluates to a `Result<(), CloseError>`, so it
+/// can be used with `?` in functions returning errors.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! try_assert_is_close {
+    ($lhs:expr, $rhs:expr $(,)?) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                if $crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol) {
+                    core::result::Result::Ok(())
+                } else {
+                    core::result::Result::Err($crate::macros::close_error(
+                        lhs, rhs, &rel_tol, &abs_tol, None,
+                    ))
+                }
+            }
+        }
+    };
+
+    ($lhs:expr, $rhs:expr, $($arg:tt)+) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                if $crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol) {
+                    core::result::Result::Ok(())
+                } else {
+                    core::result::Result::Err($crate::macros::close_error(
+                        lhs, rhs, &rel_tol, &abs_tol, Some(core::format_args!($($arg)+)),
+                    ))
+                }
+            }
+        }
+    };
+}
+
+/// Check if two values are approximately equal
+///
+/// This accepts the same tolerance arguments as [`assert_is_close!`], using
+/// the same default tolerances, and warns if the result is unused. The
+/// tolerances of [`assert_is_close_rel_tol!`][crate::assert_is_close_rel_tol],
+/// [`assert_is_close_abs_tol!`][crate::assert_is_close_abs_tol], and
+/// [`assert_is_close_tol!`][crate::assert_is_close_tol] can be given using
+/// `rel_tol = ...` and `abs_tol = ...`.
+///
+/// ```
+/// use isclose::{is_close, tol};
+///
+/// tol! {
+///     const COARSE: f64 = (1e-3, 1e-6);
+/// }
+///
+/// assert!(is_close!(0.1 + 0.2, 0.3));
+/// assert!(is_close!(22.0 / 7.0, 3.1428, named = COARSE));
+/// assert!(is_close!(1e-10, 2e-10, abs_tol_below = (1.0, 1e-9), rel_tol_above = 1e-6));
+/// assert!(is_close!(1.0, 1.0005, db = -60.0));
+/// assert!(is_close!(9.9, 10.0, pct_of = 10.0, tol = 2.0));
+/// assert!(is_close!(1.0, 1.1, rel_tol = 0.1));
+/// assert!(is_close!(1.0, 1.1, abs_tol = 0.2));
+/// assert!(is_close!(1.0, 1.1, rel_tol = 0.0, abs_tol = 0.2));
+/// ```
+#[macro_export]
+macro_rules! is_close {
+    ($lhs:expr, $rhs:expr $(,)?) => {
+        $crate::macros::must_use(match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                $crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol)
+            }
+        })
+    };
+
+    ($lhs:expr, $rhs:expr, named = $tol:expr $(,)?) => {
+        $crate::macros::must_use(match (&$lhs, &$rhs, &$tol) {
+            (lhs, rhs, tol) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let tol: &$crate::Tolerance<_> = tol;
+                $crate::IsClose::is_close_tol(lhs, rhs, &tol.rel, &tol.abs)
+            }
+        })
+    };
+
+    (
+        $lhs:expr, $rhs:expr,
+        abs_tol_below = ($threshold:expr, $abs_tol:expr), rel_tol_above = $rel_tol:expr $(,)?
+    ) => {
+        $crate::macros::must_use(match (&$lhs, &$rhs, &$threshold, &$abs_tol, &$rel_tol) {
+            (lhs, rhs, threshold, abs_tol, rel_tol) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                $crate::macros::is_close_piecewise(lhs, rhs, threshold, abs_tol, rel_tol)
+            }
+        })
+    };
+
+    ($lhs:expr, $rhs:expr, db = $db:expr $(,)?) => {
+        $crate::macros::must_use(match (&$lhs, &$rhs, &$db) {
+            (lhs, rhs, db) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let error_db = $crate::FloatIsClose::rel_error_db(lhs, rhs);
+                $crate::macros::is_within_db(error_db, *db)
+            }
+        })
+    };
+
+    ($lhs:expr, $rhs:expr, pct_of = $reference:expr, tol = $pct:expr $(,)?) => {
+        $crate::macros::must_use(match (&$lhs, &$rhs, &$reference, &$pct) {
+            (lhs, rhs, reference, pct) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let abs_tol = $crate::macros::pct_of_tol(reference, pct);
+                $crate::IsClose::is_close_abs_tol(lhs, rhs, &abs_tol)
+            }
+        })
+    };
+
+    ($lhs:expr, $rhs:expr, rel_tol = $rel_tol:expr $(,)?) => {
+        $crate::macros::must_use(match (&$lhs, &$rhs, &$rel_tol) {
+            (lhs, rhs, rel_tol) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs, rel_tol) = ((*lhs).borrow(), (*rhs).borrow(), (*rel_tol).borrow());
+                $crate::IsClose::is_close_rel_tol(lhs, rhs, rel_tol)
+            }
+        })
+    };
+
+    ($lhs:expr, $rhs:expr, abs_tol = $abs_tol:expr $(,)?) => {
+        $crate::macros::must_use(match (&$lhs, &$rhs, &$abs_tol) {
+            (lhs, rhs, abs_tol) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs, abs_tol) = ((*lhs).borrow(), (*rhs).borrow(), (*abs_tol).borrow());
+                $crate::IsClose::is_close_abs_tol(lhs, rhs, abs_tol)
+            }
+        })
+    };
+
+    ($lhs:expr, $rhs:expr, rel_tol = $rel_tol:expr, abs_tol = $abs_tol:expr $(,)?) => {
+        $crate::macros::must_use(match (&$lhs, &$rhs, &$rel_tol, &$abs_tol) {
+            (lhs, rhs, rel_tol, abs_tol) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs, rel_tol, abs_tol) = (
+                    (*lhs).borrow(),
+                    (*rhs).borrow(),
+                    (*rel_tol).borrow(),
+                    (*abs_tol).borrow(),
+                );
+                $crate::IsClose::is_close_tol(lhs, rhs, rel_tol, abs_tol)
+            }
+        })
+    };
+}
+
+/// Check if the given fields of a struct or enum variant are approximately
+/// equal to the given values, ignoring any other fields
+///
+/// The fields are compared using the same default tolerances as
+/// [`assert_is_close!`]. This returns `false` if the value doesn't match the
+/// given struct or variant.
+///
+/// ```
+/// use isclose::close_matches;
+///
+/// struct Particle { id: u32, x: f32, y: f32 }
+///
+/// let particle = Particle { id: 7, x: 0.1 + 0.2, y: 0.2 + 0.4 };
+///
+/// assert!(close_matches!(particle, Particle { x: 0.3, y: 0.6 }));
+/// assert!(!close_matches!(particle, Particle { x: 0.3, y: 0.7 }));
+/// ```
+#[macro_export]
+macro_rules! close_matches {
+    ($value:expr, $path:path { $($field:ident: $expected:expr),* $(,)? } $(,)?) => {
+        match &$value {
+            #[allow(unused_variables)]
+            $path { $($field,)* .. } => true $(&& {
+                let (rel_tol, abs_tol) = $crate::macros::default_tol($field);
+                $crate::IsClose::is_close_tol($field, &$expected, &rel_tol, &abs_tol)
+            })*,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    };
+}
+
+/// Implement [`IsClose`][crate::IsClose] for a struct by comparing the given
+/// fields
+///
+/// The implementation uses the given tolerance type, and that type's default
+/// tolerances. Every listed field must implement `IsClose` with the same
+/// tolerance type.
+///
+/// For structs with fields of different precisions, `widen = T` can be used
+/// instead of `tol = T`, where `T` is the widest field type. Each field is
+/// then converted to `T` using [`From`] before comparing, so the fields must
+/// be [`Copy`], and `T`'s tolerances are used throughout. For example, the
+/// `f32` fields of a struct with `widen = f64` are compared as `f64`s using
+/// `f64`'s stricter default tolerances.
+///
+/// ```
+/// use isclose::{assert_is_close, impl_is_close};
+///
+/// #[derive(Debug)]
+/// struct Vector { x: f32, y: f32 }
+///
+/// impl_is_close!(Vector { x, y }, tol = f32);
+///
+/// assert_is_close!(Vector { x: 0.1 + 0.2, y: 0.2 + 0.4 }, Vector { x: 0.3, y: 0.6 });
+/// ```
+#[macro_export]
+macro_rules! impl_is_close {
+    ($type:ty { $($field:tt),+ $(,)? }, tol = $tol:ty $(,)?) => {
+        impl $crate::IsClose<$tol> for $type {
+            const ABS_TOL: $tol = <$tol as $crate::IsClose<$tol>>::ABS_TOL;
+            const REL_TOL: $tol = <$tol as $crate::IsClose<$tol>>::REL_TOL;
+
+            #[inline]
+            fn is_close_tol(
+                &self,
+                other: impl core::borrow::Borrow<Self>,
+                rel_tol: impl core::borrow::Borrow<$tol>,
+                abs_tol: impl core::borrow::Borrow<$tol>,
+            ) -> bool {
+                let (other, rel_tol, abs_tol): (&Self, &$tol, &$tol) =
+                    (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+                true $(&& $crate::IsClose::<$tol>::is_close_tol(
+                    &self.$field, &other.$field, rel_tol, abs_tol,
+                ))+
+            }
+        }
+    };
+
+    ($type:ty { $($field:tt),+ $(,)? }, widen = $tol:ty $(,)?) => {
+        impl $crate::IsClose<$tol> for $type {
+            const ABS_TOL: $tol = <$tol as $crate::IsClose<$tol>>::ABS_TOL;
+            const REL_TOL: $tol = <$tol as $crate::IsClose<$tol>>::REL_TOL;
+
+            #[inline]
+            fn is_close_tol(
+                &self,
+                other: impl core::borrow::Borrow<Self>,
+                rel_tol: impl core::borrow::Borrow<$tol>,
+                abs_tol: impl core::borrow::Borrow<$tol>,
+            ) -> bool {
+                let (other, rel_tol, abs_tol): (&Self, &$tol, &$tol) =
+                    (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+                true $(&& $crate::IsClose::<$tol>::is_close_tol(
+                    &<$tol as core::convert::From<_>>::from(self.$field),
+                    &<$tol as core::convert::From<_>>::from(other.$field),
+                    rel_tol,
+                    abs_tol,
+                ))+
+            }
+        }
+    };
+}
+
+/// Declare named [`Tolerance`][crate::Tolerance] constants
+///
+/// ```
+/// use isclose::{assert_is_close, tol};
+///
+/// tol! {
+///     /// Tolerance for quick approximations
+///     const COARSE: f64 = (1e-3, 1e-6);
+///     pub const FINE: f64 = (1e-12, 1e-15);
+/// }
+///
+/// assert_is_close!(22.0 / 7.0, 3.1428, named = COARSE);
+/// ```
+#[macro_export]
+macro_rules! tol {
+    ($($(#[$meta:meta])* $vis:vis const $name:ident: $t:ty = ($rel:expr, $abs:expr);)+) => {
+        $(
+            $(#[$meta])*
+            $vis const $name: $crate::Tolerance<$t> = $crate::Tolerance::new($rel, $abs);
+        )+
+    };
+}
+
 /// Assert that two values are approximately equal using the given relative
 /// tolerance
 #[macro_export]
@@ -175,46 +1279,410 @@ macro_rules! assert_is_close_tol {
                 }
             }
         }
-    };
-}
+    };
+}
+
+/// Assert that several pairs of values are approximately equal, reporting
+/// every failed comparison rather than stopping at the first
+///
+/// Each comparison is written as `lhs ~= rhs` and separated by semicolons.
+/// All comparisons are evaluated using the default tolerances, as with
+/// [`assert_is_close!`], before panicking once with the details of every
+/// failure.
+///
+/// The panic location is that of the macro invocation, so failed comparisons
+/// are identified in the message by their (1-based) index and source text.
+///
+/// ```should_panic
+/// use isclose::assert_close_group;
+///
+/// assert_close_group! {
+///     0.1 + 0.2 ~= 0.3;
+///     1.0 ~= 1.1;
+///     2.0_f32 ~= 2.2;
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! assert_close_group {
+    (@check $group:ident [$($lhs:tt)+] $rhs:expr) => {
+        match (&($($lhs)+), &$rhs) {
+            (lhs, rhs) => {
+                use core::borrow::Borrow;
+                let (lhs, rhs) = ((*lhs).borrow(), (*rhs).borrow());
+                let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                $group.record(
+                    $crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol),
+                    core::concat!(core::stringify!($($lhs)+), " ~= ", core::stringify!($rhs)),
+                    lhs, rhs, &rel_tol, &abs_tol,
+                );
+            }
+        }
+    };
+
+    (@munch $group:ident [$($lhs:tt)+] ~ = $rhs:expr $(; $($rest:tt)*)?) => {
+        $crate::assert_close_group!(@check $group [$($lhs)+] $rhs);
+        $crate::assert_close_group!(@munch $group [] $($($rest)*)?);
+    };
+
+    (@munch $group:ident [$($lhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_close_group!(@munch $group [$($lhs)* $next] $($rest)*);
+    };
+
+    (@munch $group:ident []) => {};
+
+    ($($body:tt)+) => {{
+        let mut group = $crate::macros::GroupFailures::default();
+        $crate::assert_close_group!(@munch group [] $($body)+);
+        group.finish();
+    }};
+}
+
+/// Assert that two structs are equal, comparing some fields exactly using
+/// [`PartialEq`] and others approximately using [`IsClose`][crate::IsClose]
+///
+/// The approximately compared fields use the default tolerances, as with
+/// [`assert_is_close!`]. All fields are compared before panicking, and the
+/// message lists every differing field and whether it differed exactly or
+/// approximately.
+///
+/// ```
+/// use isclose::assert_fields_close;
+///
+/// #[derive(Debug)]
+/// struct Sample { id: u32, value: f64 }
+///
+/// assert_fields_close!(
+///     Sample { id: 1, value: 0.1 + 0.2 },
+///     Sample { id: 1, value: 0.3 },
+///     exact = { id },
+///     close = { value },
+/// );
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! assert_fields_close {
+    (
+        $lhs:expr, $rhs:expr,
+        exact = { $($exact:tt),* $(,)? }, close = { $($close:tt),* $(,)? } $(,)?
+    ) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                let mut fields = $crate::macros::FieldFailures::default();
+                $(fields.exact(core::stringify!($exact), &lhs.$exact, &rhs.$exact);)*
+                $({
+                    let (lhs, rhs) = (&lhs.$close, &rhs.$close);
+                    let (rel_tol, abs_tol) = $crate::macros::default_tol(lhs);
+                    fields.close(
+                        $crate::IsClose::is_close_tol(lhs, rhs, &rel_tol, &abs_tol),
+                        core::stringify!($close),
+                        lhs, rhs, &rel_tol, &abs_tol,
+                    );
+                })*
+                fields.finish();
+            }
+        }
+    };
+}
+
+/// Assert that the last element of a sequence is approximately equal to the
+/// target using the given relative and absolute tolerances
+///
+/// This is intended for convergence tests of iterative algorithms. If
+/// `monotonic` is given, the differences between consecutive elements must
+/// also be non-increasing. The sequence can be anything which borrows as a
+/// slice, and must not be empty.
+///
+/// ```
+/// use isclose::assert_converges_close;
+///
+/// // Newton's method for the square root of 2
+/// let mut estimates = vec![1.0_f64];
+/// for _ in 0..5 {
+///     let x = estimates[estimates.len() - 1];
+///     estimates.push((x + 2.0 / x) / 2.0);
+/// }
+///
+/// assert_converges_close!(estimates, 2.0_f64.sqrt(), 1e-12, 0.0, monotonic);
+/// ```
+#[macro_export]
+macro_rules! assert_converges_close {
+    ($sequence:expr, $target:expr, $rel_tol:expr, $abs_tol:expr $(,)?) => {
+        $crate::assert_converges_close!(@check $sequence, $target, $rel_tol, $abs_tol, false)
+    };
+
+    ($sequence:expr, $target:expr, $rel_tol:expr, $abs_tol:expr, monotonic $(,)?) => {
+        $crate::assert_converges_close!(@check $sequence, $target, $rel_tol, $abs_tol, true)
+    };
+
+    (@check $sequence:expr, $target:expr, $rel_tol:expr, $abs_tol:expr, $monotonic:expr) => {
+        match (&$sequence, &$target, &$rel_tol, &$abs_tol) {
+            (sequence, target, rel_tol, abs_tol) => {
+                use core::borrow::Borrow;
+                let sequence: &[_] = (*sequence).borrow();
+                $crate::macros::assert_converges(sequence, target, rel_tol, abs_tol, $monotonic);
+            }
+        }
+    };
+}
+
+/// Assert that a value is approximately equal to itself after round-tripping
+/// it through `encode` and then `decode`, using the given relative and
+/// absolute tolerances
+///
+/// On failure, the original value is shown on the left and the round-tripped
+/// value on the right. See also [`round_trip_is_close`][crate::round_trip_is_close].
+///
+/// ```
+/// use isclose::assert_round_trips_close;
+///
+/// // Store as 16-bit fixed point with 8 fractional bits
+/// let encode = |x: &f64| (x * 256.0).round() as i16;
+/// let decode = |q: &i16| f64::from(*q) / 256.0;
+///
+/// assert_round_trips_close!(3.14159, encode, decode, 0.0, 0.5 / 256.0);
+/// ```
+#[macro_export]
+macro_rules! assert_round_trips_close {
+    ($value:expr, $encode:expr, $decode:expr, $rel_tol:expr, $abs_tol:expr $(,)?) => {
+        match (&$value, &$rel_tol, &$abs_tol) {
+            (value, rel_tol, abs_tol) => {
+                $crate::macros::assert_round_trips(value, $encode, $decode, rel_tol, abs_tol);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::PI;
+
+    #[test]
+    fn assert_is_close() {
+        assert_is_close!(PI, 355.0 / 113.0);
+        assert_is_close!(&PI, 355.0 / 113.0);
+        assert_is_close!(PI, &(355.0 / 113.0));
+        assert_is_close!(&PI, &(355.0 / 113.0));
+    }
+
+    #[test]
+    fn assert_is_close_error() {
+        let err = std::panic::catch_unwind(|| assert_is_close!(2.0_f32, 3.0)).unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert_eq!(
+            msg,
+            &format!(
+                "assertion `left ~= right` failed
+    left: 2.0
+   right: 3.0
+ rel tol: {0:?}
+ abs tol: {0:?}",
+                1e-6
+            ) // Rust <=1.57 formats this differently, so we need to use format!()
+        );
+
+        let err =
+            std::panic::catch_unwind(|| assert_is_close!(2.0_f32, 3.0, "message")).unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert_eq!(
+            msg,
+            &format!(
+                "assertion `left ~= right` failed: message
+    left: 2.0
+   right: 3.0
+ rel tol: {0:?}
+ abs tol: {0:?}",
+                1e-6
+            ) // Rust <=1.57 formats this differently, so we need to use format!()
+        );
+    }
+
+    #[test]
+    fn assert_failed_unsized() {
+        let lhs: &[f64] = &[1.0, 2.0];
+        let err = std::panic::catch_unwind(|| {
+            super::assert_failed(
+                lhs,
+                &[1.0, 2.5][..],
+                Some(&0.1),
+                None,
+                Some(format_args!("{}", 1)),
+            )
+        })
+        .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert_eq!(
+            msg,
+            "assertion `left ~= right` failed: 1
+    left: [1.0, 2.0]
+   right: [1.0, 2.5]
+ rel tol: 0.1
+ abs tol: 0.0"
+        );
+    }
+
+    #[test]
+    fn assert_is_close_display() {
+        use core::borrow::Borrow;
+        use core::fmt;
+
+        use crate::IsClose;
+
+        struct Metres(f32);
+
+        impl fmt::Display for Metres {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} m", self.0)
+            }
+        }
+
+        impl IsClose<f32> for Metres {
+            const ABS_TOL: f32 = <f32 as IsClose>::ABS_TOL;
+            const REL_TOL: f32 = <f32 as IsClose>::REL_TOL;
+
+            fn is_close_tol(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<f32>,
+                abs_tol: impl Borrow<f32>,
+            ) -> bool {
+                self.0.is_close_tol(other.borrow().0, rel_tol, abs_tol)
+            }
+        }
 
-#[cfg(test)]
-mod tests {
-    use core::f32::consts::PI;
+        assert_is_close_display!(Metres(0.1 + 0.2), Metres(0.3));
+        assert_is_close_display!(Metres(0.1 + 0.2), Metres(0.3), "{}", "message");
+
+        let err = std::panic::catch_unwind(|| {
+            assert_is_close_display!(Metres(2.0), Metres(3.0), "{}", "message");
+        })
+        .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert_eq!(
+            msg,
+            &format!(
+                "assertion `left ~= right` failed: message
+    left: 2 m
+   right: 3 m
+ rel tol: {}
+ abs tol: {}",
+                f32::REL_TOL,
+                f32::ABS_TOL,
+            )
+        );
+    }
 
     #[test]
-    fn assert_is_close() {
-        assert_is_close!(PI, 355.0 / 113.0);
-        assert_is_close!(&PI, 355.0 / 113.0);
-        assert_is_close!(PI, &(355.0 / 113.0));
-        assert_is_close!(&PI, &(355.0 / 113.0));
+    fn assert_is_close_min() {
+        assert_is_close_min!(PI, 355.0 / 113.0);
+        assert_is_close_min!(&PI, &(355.0 / 113.0));
+
+        let err = std::panic::catch_unwind(|| assert_is_close_min!(2.0_f32, 3.0)).unwrap_err();
+        let msg: &&str = err.downcast_ref().unwrap();
+
+        assert_eq!(*msg, "assertion `left ~= right` failed");
     }
 
     #[test]
-    fn assert_is_close_error() {
-        let err = std::panic::catch_unwind(|| assert_is_close!(2.0_f32, 3.0)).unwrap_err();
+    #[cfg(feature = "std")]
+    fn assert_close_group() {
+        assert_close_group! {
+            PI ~= 355.0 / 113.0;
+            0.1 + 0.2 ~= 0.3_f64;
+            &PI ~= &(355.0 / 113.0)
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn assert_close_group_error() {
+        let err = std::panic::catch_unwind(|| {
+            assert_close_group! {
+                2.0_f32 ~= 3.0;
+                1.0_f32 ~= 1.0;
+                4.0_f32 ~= 5.0;
+            }
+        })
+        .unwrap_err();
         let msg: &String = err.downcast_ref().unwrap();
 
         assert_eq!(
             msg,
             &format!(
-                "assertion `left ~= right` failed
+                "assertion `left ~= right` failed for 2 of 3 comparisons
+ [1] 2.0_f32 ~= 3.0
     left: 2.0
    right: 3.0
  rel tol: {0:?}
+ abs tol: {0:?}
+ [3] 4.0_f32 ~= 5.0
+    left: 4.0
+   right: 5.0
+ rel tol: {0:?}
  abs tol: {0:?}",
                 1e-6
             ) // Rust <=1.57 formats this differently, so we need to use format!()
         );
+    }
 
-        let err =
-            std::panic::catch_unwind(|| assert_is_close!(2.0_f32, 3.0, "message")).unwrap_err();
+    #[test]
+    #[cfg(feature = "std")]
+    fn assert_fields_close() {
+        #[derive(Debug)]
+        struct Sample {
+            id: u32,
+            value: f32,
+        }
+
+        assert_fields_close!(
+            Sample { id: 1, value: PI },
+            Sample {
+                id: 1,
+                value: 355.0 / 113.0
+            },
+            exact = { id },
+            close = { value },
+        );
+
+        let err = std::panic::catch_unwind(|| {
+            assert_fields_close!(
+                Sample { id: 1, value: 2.0 },
+                Sample { id: 2, value: 2.0 },
+                exact = { id },
+                close = { value },
+            );
+        })
+        .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert_eq!(
+            msg,
+            "assertion `left ~= right` failed for fields
+ `id` differs exactly
+    left: 1
+   right: 2"
+        );
+
+        let err = std::panic::catch_unwind(|| {
+            assert_fields_close!(
+                Sample { id: 1, value: 2.0 },
+                Sample { id: 1, value: 3.0 },
+                exact = { id },
+                close = { value },
+            );
+        })
+        .unwrap_err();
         let msg: &String = err.downcast_ref().unwrap();
 
         assert_eq!(
             msg,
             &format!(
-                "assertion `left ~= right` failed: message
+                "assertion `left ~= right` failed for fields
+ `value` differs approximately
     left: 2.0
    right: 3.0
  rel tol: {0:?}
@@ -224,6 +1692,434 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "json-report")]
+    #[test]
+    fn json_report() {
+        use super::{json_report, json_report_db};
+
+        assert_eq!(
+            json_report(&2.0_f32, &3.5, &1e-6, &1e-6),
+            r#"{"left":"2.0","right":"3.5","rel_tol":"1e-6","abs_tol":"1e-6","abs_diff":1.5}"#
+        );
+        assert_eq!(
+            json_report(&Some("a\"b"), &None::<&str>, &0.1, &0.0),
+            r#"{"left":"Some(\"a\\\"b\")","right":"None","rel_tol":"0.1","abs_tol":"0.0","abs_diff":null}"#
+        );
+        assert_eq!(
+            json_report_db(&2.0_f64, &1.0, -6.5, -60.0),
+            r#"{"left":"2.0","right":"1.0","error_db":-6.5,"max_db":-60,"abs_diff":1}"#
+        );
+        assert_eq!(
+            json_report_db(&f64::NAN, &1.0, f64::NAN, -60.0),
+            r#"{"left":"NaN","right":"1.0","error_db":null,"max_db":-60,"abs_diff":null}"#
+        );
+    }
+
+    #[cfg(all(feature = "json-report", feature = "serde"))]
+    #[test]
+    fn json_report_parses() {
+        use super::json_report;
+        use serde_json::{json, Value};
+
+        /// Formats as the raw string, since `Debug` implementations aren't
+        /// required to escape control characters
+        struct Raw(&'static str);
+        impl core::fmt::Debug for Raw {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        let report: Value =
+            serde_json::from_str(&json_report(&2.0_f32, &3.5, &1e-6, &0.0)).unwrap();
+        assert_eq!(
+            report,
+            json!({
+                "left": "2.0",
+                "right": "3.5",
+                "rel_tol": "1e-6",
+                "abs_tol": "0.0",
+                "abs_diff": 1.5,
+            })
+        );
+
+        let report: Value =
+            serde_json::from_str(&json_report(&"a\"b\\c\n\td\u{7f}", &f64::NAN, &0.1, &0.0))
+                .unwrap();
+        assert_eq!(report["left"], r#""a\"b\\c\n\td\u{7f}""#);
+        assert_eq!(report["right"], "NaN");
+        assert_eq!(report["abs_diff"], Value::Null);
+
+        let report: Value = serde_json::from_str(&json_report(
+            &Raw("a\n\"b\"\t\u{1}"),
+            &Raw("\\"),
+            &0.1,
+            &0.0,
+        ))
+        .unwrap();
+        assert_eq!(report["left"], "a\n\"b\"\t\u{1}");
+        assert_eq!(report["right"], "\\");
+    }
+
+    #[test]
+    fn assert_is_close_location() {
+        // The reported location is checked by tests/track_caller.rs, since it
+        // is only observable by replacing the global panic hook
+        #[track_caller]
+        fn helper(lhs: f32, rhs: f32) {
+            assert_is_close!(lhs, rhs);
+        }
+
+        helper(1.0, 1.0);
+
+        let err = std::panic::catch_unwind(|| helper(2.0, 3.0)).unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert!(msg.starts_with("assertion `left ~= right` failed\n    left: 2.0\n"));
+    }
+
+    #[cfg(feature = "defmt")]
+    #[allow(dead_code)] // Only compile-checked since running requires a defmt logger
+    fn defmt_assert_is_close() {
+        defmt_assert_is_close!(PI, 355.0 / 113.0);
+        defmt_assert_is_close!(&PI, 355.0 / 113.0);
+        defmt_assert_is_close!(PI, &(355.0 / 113.0));
+        defmt_assert_is_close!(&PI, &(355.0 / 113.0));
+    }
+
+    #[test]
+    fn assert_is_close_named() {
+        tol! {
+            const COARSE: f32 = (1e-2, 1e-2);
+            const FINE: f32 = (1e-6, 1e-6);
+        }
+
+        assert_is_close!(PI, 22.0 / 7.0, named = COARSE);
+        assert_is_close!(&PI, &(22.0 / 7.0), named = &COARSE);
+
+        let err = std::panic::catch_unwind(|| assert_is_close!(PI, 22.0 / 7.0, named = FINE))
+            .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert_eq!(
+            msg,
+            &format!(
+                "assertion `left ~= right` failed
+    left: 3.1415927
+   right: 3.142857
+ rel tol: {0:?}
+ abs tol: {0:?}",
+                1e-6
+            ) // Rust <=1.57 formats this differently, so we need to use format!()
+        );
+
+        let err = std::panic::catch_unwind(|| {
+            assert_is_close!(PI, 22.0 / 7.0, named = FINE, "{}", "fine");
+        })
+        .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+
+        assert!(msg.starts_with("assertion `left ~= right` failed: fine\n"));
+    }
+
+    #[test]
+    fn assert_is_close_piecewise() {
+        let computed = [
+            1e-12 + 1e-13,
+            0.5 + 1e-10,
+            1e3 * (1.0 + 1e-7),
+            1e9 * (1.0 + 1e-7),
+        ];
+        let expected = [1e-12, 0.5, 1e3, 1e9];
+
+        for (lhs, rhs) in computed.iter().zip(expected) {
+            assert_is_close!(lhs, rhs, abs_tol_below = (1.0, 1e-9), rel_tol_above = 1e-6);
+            assert_is_close!(
+                lhs,
+                rhs,
+                abs_tol_below = (1.0, 1e-9),
+                rel_tol_above = 1e-6,
+                "{} != {}",
+                lhs,
+                rhs,
+            );
+        }
+
+        // Above the threshold the absolute tolerance no longer applies
+        let err = std::panic::catch_unwind(|| {
+            assert_is_close!(
+                1e3,
+                1e3 + 1e-3,
+                abs_tol_below = (1e3, 1.0),
+                rel_tol_above = 1e-9
+            );
+        })
+        .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+        assert_eq!(
+            msg,
+            &format!(
+                "assertion `left ~= right` failed
+    left: 1000.0
+   right: 1000.001
+ abs tol: 1.0 below 1000.0
+ rel tol: {:?} above 1000.0",
+                1e-9
+            ) // Rust <=1.57 formats this differently, so we need to use format!()
+        );
+
+        // And below the threshold the relative tolerance no longer applies
+        let err = std::panic::catch_unwind(|| {
+            assert_is_close!(
+                1e-6,
+                2e-6,
+                abs_tol_below = (1.0, 1e-9),
+                rel_tol_above = 1.0,
+                "tiny"
+            );
+        })
+        .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+        assert_eq!(
+            msg,
+            &format!(
+                "assertion `left ~= right` failed: tiny
+    left: {:?}
+   right: {:?}
+ abs tol: {:?} below 1.0
+ rel tol: 1.0 above 1.0",
+                1e-6, 2e-6, 1e-9
+            ) // Rust <=1.57 formats this differently, so we need to use format!()
+        );
+    }
+
+    #[test]
+    fn assert_is_close_pct_of() {
+        let (measured, expected) = (98.5, 100.0);
+
+        assert_is_close!(measured, expected, pct_of = expected, tol = 2.0);
+        assert_is_close!(-measured, -expected, pct_of = -expected, tol = 2.0);
+        assert_is_close!(measured, expected, pct_of = expected, tol = 2.0, "{}", 1);
+
+        // A symmetric relative tolerance scales by the larger value, so is
+        // more lenient when the measured value is larger than expected
+        let (measured, expected) = (102.02, 100.0);
+        assert_is_close_rel_tol!(measured, expected, 0.02);
+        let err = std::panic::catch_unwind(|| {
+            assert_is_close!(measured, expected, pct_of = expected, tol = 2.0);
+        })
+        .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+        assert_eq!(
+            msg,
+            "assertion `left ~= right` failed
+    left: 102.02
+   right: 100.0
+ rel tol: 0.0
+ abs tol: 2.0"
+        );
+
+        // The reference can be any value, e.g. the full scale of an instrument
+        assert_is_close!(1.5_f32, 1.0, pct_of = 50.0, tol = 1.0);
+    }
+
+    #[test]
+    fn assert_is_close_db() {
+        assert_is_close!(1.0009, 1.0, db = -60.0);
+        assert_is_close!(1.0009_f32, 1.0, db = -60.0, "{}", 1);
+        assert_is_close!(0.0, 0.0, db = -60.0);
+
+        let err = std::panic::catch_unwind(|| assert_is_close!(2.0, 1.0, db = -40.0)).unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+        assert_eq!(
+            msg,
+            "assertion `left ~= right` failed
+    left: 2.0
+   right: 1.0
+   error: 0.0 dB
+ max err: -40.0 dB"
+        );
+
+        let err = std::panic::catch_unwind(|| assert_is_close!(1e-9, 0.0, db = -60.0, "zero"))
+            .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+        assert!(msg.starts_with("assertion `left ~= right` failed: zero\n"));
+        assert!(msg.contains("   error: inf dB\n"));
+    }
+
+    #[cfg(any(feature = "env-tol", feature = "global-config"))]
+    #[test]
+    fn assert_is_close_tol_overrides() {
+        // Overrides only apply to float tolerances, but any tolerance type can
+        // still be used with the feature enabled
+        assert_is_close!((1.0_f32, 2.0_f64), (1.0, 2.0));
+        crate::assert_is_close_abs_tol!((1.0_f32, 2.0_f64), (1.1, 2.0), (0.2, 0.0));
+    }
+
+    #[test]
+    fn impl_is_close() {
+        use crate::IsClose;
+
+        #[derive(Debug)]
+        struct Particle {
+            pos: (f64, f64),
+            mass: f64,
+            charge: f64,
+        }
+
+        impl_is_close!(Particle { mass, charge }, tol = f64);
+
+        #[derive(Debug)]
+        struct Pair(f32, f32);
+
+        impl_is_close!(Pair { 0, 1 }, tol = f32);
+
+        let particle1 = Particle {
+            pos: (0.0, 0.0),
+            mass: 0.1 + 0.2,
+            charge: -1.0,
+        };
+        let particle2 = Particle {
+            pos: (1.0, 1.0),
+            mass: 0.3,
+            charge: -1.0,
+        };
+        let particle3 = Particle {
+            pos: (0.0, 0.0),
+            mass: 0.3,
+            charge: 1.0,
+        };
+
+        assert_is_close!(particle1, particle2);
+        assert!(!particle1.is_close(&particle3));
+        assert_eq!(particle1.pos, particle3.pos);
+
+        assert_is_close!(Pair(0.1 + 0.2, 1.0), Pair(0.3, 1.0));
+        assert!(!Pair(0.1, 1.0).is_close(Pair(0.1, 2.0)));
+    }
+
+    #[test]
+    fn impl_is_close_widen() {
+        use crate::IsClose;
+
+        #[derive(Debug)]
+        struct Mixed {
+            a: f32,
+            b: f64,
+        }
+
+        impl_is_close!(Mixed { a, b }, widen = f64);
+
+        assert_eq!(Mixed::REL_TOL.to_bits(), f64::REL_TOL.to_bits());
+        assert_eq!(Mixed::ABS_TOL.to_bits(), f64::ABS_TOL.to_bits());
+
+        // Within f32's tolerance, but not f64's
+        let a = 1.0_f32 + f32::EPSILON;
+        assert!(a.is_close(1.0));
+        assert!(!Mixed { a, b: 1.0 }.is_close(Mixed { a: 1.0, b: 1.0 }));
+        assert!(Mixed { a, b: 1.0 }.is_close_rel_tol(Mixed { a: 1.0, b: 1.0 }, 1e-6));
+
+        assert_is_close!(
+            Mixed {
+                a: 0.5,
+                b: 0.1 + 0.2
+            },
+            Mixed { a: 0.5, b: 0.3 }
+        );
+    }
+
+    #[test]
+    fn is_close() {
+        tol! {
+            const COARSE: f32 = (1e-2, 1e-2);
+        }
+
+        assert!(is_close!(0.1_f32 + 0.2, 0.3));
+        assert!(is_close!(&(0.1_f32 + 0.2), 0.3));
+        assert!(!is_close!(PI, 22.0 / 7.0));
+
+        assert!(is_close!(PI, 22.0 / 7.0, named = COARSE));
+        assert!(!is_close!(PI, 3.0, named = COARSE));
+
+        assert!(is_close!(PI, 22.0 / 7.0, rel_tol = 1e-3));
+        assert!(!is_close!(PI, 22.0 / 7.0, rel_tol = 1e-4));
+
+        assert!(is_close!(PI, 22.0 / 7.0, abs_tol = 1e-2));
+        assert!(!is_close!(PI, 22.0 / 7.0, abs_tol = 1e-3));
+
+        assert!(is_close!(PI, 22.0 / 7.0, rel_tol = 0.0, abs_tol = 1e-2));
+        assert!(!is_close!(PI, 22.0 / 7.0, rel_tol = 1e-4, abs_tol = 1e-4));
+
+        let (threshold, abs_tol, rel_tol) = (1.0, 1e-6, 1e-3);
+        assert!(is_close!(
+            1e-7_f32,
+            2e-7,
+            abs_tol_below = (threshold, abs_tol),
+            rel_tol_above = rel_tol
+        ));
+        assert!(!is_close!(
+            1e-7_f32,
+            2e-6,
+            abs_tol_below = (threshold, abs_tol),
+            rel_tol_above = rel_tol
+        ));
+        assert!(is_close!(
+            1e3_f32,
+            1000.5,
+            abs_tol_below = (threshold, abs_tol),
+            rel_tol_above = rel_tol
+        ));
+        assert!(!is_close!(
+            1e3_f32,
+            1002.0,
+            abs_tol_below = (threshold, abs_tol),
+            rel_tol_above = rel_tol
+        ));
+
+        assert!(is_close!(1.0_f64, 1.0005, db = -60.0));
+        assert!(!is_close!(1.0_f64, 1.002, db = -60.0));
+
+        assert!(is_close!(9.9_f32, 10.0, pct_of = 10.0, tol = 2.0));
+        assert!(!is_close!(9.7_f32, 10.0, pct_of = 10.0, tol = 2.0));
+    }
+
+    #[test]
+    fn close_matches() {
+        #[derive(Debug)]
+        struct Vector {
+            x: f32,
+            y: f32,
+            label: &'static str,
+        }
+
+        #[derive(Debug)]
+        enum Shape {
+            Circle { radius: f32 },
+            Square { side: f32 },
+        }
+
+        let vec = Vector {
+            x: 0.1 + 0.2,
+            y: 0.2 + 0.4,
+            label: "vec",
+        };
+
+        assert!(close_matches!(vec, Vector { x: 0.3, y: 0.6 }));
+        assert!(close_matches!(vec, Vector { y: 0.6 }));
+        assert!(close_matches!(&vec, Vector { x: 0.3 }));
+        assert!(!close_matches!(vec, Vector { x: 0.3, y: 0.7 }));
+        assert_eq!(vec.label, "vec");
+
+        let shape = Shape::Circle { radius: 0.1 + 0.2 };
+
+        assert!(close_matches!(shape, Shape::Circle { radius: 0.3 }));
+        assert!(!close_matches!(shape, Shape::Square { side: 0.3 }));
+        assert!(!close_matches!(
+            Shape::Square { side: 0.3 },
+            Shape::Circle { radius: 0.3 }
+        ));
+    }
+
     #[test]
     fn assert_is_close_rel_tol() {
         assert_is_close_rel_tol!(1.0, 1.0 + 1e-2, 1e-1);
@@ -367,4 +2263,74 @@ mod tests {
             ) // Rust <=1.57 formats this differently, so we need to use format!()
         );
     }
+
+    #[test]
+    fn assert_round_trips_close() {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // values are in 0..=1
+        let encode = |x: &f32| (x * 15.0).round() as u8;
+        let decode = |q: &u8| f32::from(*q) / 15.0;
+
+        assert_round_trips_close!(0.45, encode, decode, 0.0, 0.5 / 15.0);
+        assert_round_trips_close!(0.2, encode, decode, 0.0, 0.0);
+        assert_round_trips_close!(1.0_f32, encode, decode, 0.0, 0.0,);
+
+        let err = std::panic::catch_unwind(|| {
+            assert_round_trips_close!(0.45, encode, decode, 0.0, 1e-3);
+        })
+        .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+        assert_eq!(
+            msg,
+            "assertion `left ~= right` failed: value does not round trip
+    left: 0.45
+   right: 0.46666667
+ rel tol: 0.0
+ abs tol: 0.001"
+        );
+    }
+
+    #[test]
+    fn assert_converges_close() {
+        let converging = [1.0, 1.5, 1.75, 1.875, 1.9375, 1.999_999_999];
+
+        assert_converges_close!(converging, 2.0, 1e-6, 0.0);
+        assert_converges_close!(converging, 2.0, 1e-6, 0.0, monotonic);
+        assert_converges_close!(&converging[..], 2.0, 1e-6, 0.0, monotonic);
+        assert_converges_close!(converging.to_vec(), 2.0, 1e-6, 0.0, monotonic);
+        assert_converges_close!([3.0_f32], 3.0, 0.0, 0.0, monotonic);
+
+        // Ends close to the target, but only by chance
+        let oscillating = [1.0, 2.1, 1.8, 2.3, 2.0];
+        assert_converges_close!(oscillating, 2.0, 1e-6, 0.0);
+        let err = std::panic::catch_unwind(|| {
+            assert_converges_close!(oscillating, 2.0, 1e-6, 0.0, monotonic);
+        })
+        .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+        assert!(msg.starts_with(
+            "assertion failed: sequence differences are not non-increasing\n    step 1: "
+        ));
+
+        let diverging = [1.0, 2.0, 4.0, 8.0];
+        let err = std::panic::catch_unwind(|| {
+            assert_converges_close!(diverging, 2.0, 1e-6, 0.0);
+        })
+        .unwrap_err();
+        let msg: &String = err.downcast_ref().unwrap();
+        assert_eq!(
+            msg,
+            "assertion `left ~= right` failed: sequence does not converge to target
+    left: 8.0
+   right: 2.0
+ rel tol: 1e-6
+ abs tol: 0.0"
+        );
+
+        let err = std::panic::catch_unwind(|| {
+            assert_converges_close!(Vec::<f64>::new(), 2.0, 1e-6, 0.0);
+        })
+        .unwrap_err();
+        let msg: &&str = err.downcast_ref().unwrap();
+        assert_eq!(*msg, "assertion failed: sequence is empty");
+    }
 }
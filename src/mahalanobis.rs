@@ -0,0 +1,127 @@
+use core::borrow::Borrow;
+
+use crate::Zero;
+
+/// Trait for comparing statistical estimates with correlated errors using the
+/// Mahalanobis distance
+///
+/// The squared Mahalanobis distance between two vectors `a` and `b` is
+/// `(a - b)ᵀ Σ⁻¹ (a - b)`, where `Σ⁻¹` is the inverse of the covariance
+/// matrix of the estimate. This weights the difference in each direction by
+/// the expected error in that direction, so errors along a strongly correlated
+/// direction are penalised less than the same errors across it. For normally
+/// distributed errors the squared distance follows a chi-squared distribution
+/// with `N` degrees of freedom, so the threshold is typically taken from its
+/// quantiles.
+///
+/// This is implemented for arrays of [`f32`] and [`f64`].
+///
+/// ```
+/// use isclose::MahalanobisIsClose;
+///
+/// // Variances of 4 and 1, so the first component is expected to vary more
+/// let inv_cov = [[0.25, 0.0], [0.0, 1.0]];
+///
+/// // 5.991 is the 95% quantile of the chi-squared distribution with 2 degrees
+/// // of freedom
+/// assert!([1.0, 2.0].is_close_mahalanobis([4.0, 2.0], inv_cov, 5.991));
+/// assert!(![1.0, 2.0].is_close_mahalanobis([1.0, 5.0], inv_cov, 5.991));
+/// ```
+pub trait MahalanobisIsClose<T, const N: usize> {
+    /// The squared Mahalanobis distance between two values, given the inverse
+    /// of their covariance matrix
+    fn squared_mahalanobis(&self, other: &Self, inv_cov: &[[T; N]; N]) -> T;
+
+    /// Check if the squared Mahalanobis distance between two values is within
+    /// the given chi-squared threshold, given the inverse of their covariance
+    /// matrix
+    fn is_close_mahalanobis(
+        &self,
+        other: impl Borrow<Self>,
+        inv_cov: impl Borrow<[[T; N]; N]>,
+        chi2_thresh: impl Borrow<T>,
+    ) -> bool;
+}
+
+macro_rules! impl_mahalanobis_is_close {
+    ($($float:ty),+) => {$(
+        impl<const N: usize> MahalanobisIsClose<$float, N> for [$float; N] {
+            #[inline]
+            fn squared_mahalanobis(&self, other: &Self, inv_cov: &[[$float; N]; N]) -> $float {
+                let mut diff = [<$float>::ZERO; N];
+                for (diff, (lhs, rhs)) in diff.iter_mut().zip(self.iter().zip(other)) {
+                    *diff = lhs - rhs;
+                }
+                inv_cov.iter().zip(&diff).fold(<$float>::ZERO, |acc, (row, di)| {
+                    acc + di * row.iter().zip(&diff).fold(<$float>::ZERO, |acc, (m, dj)| acc + m * dj)
+                })
+            }
+
+            #[inline]
+            fn is_close_mahalanobis(
+                &self,
+                other: impl Borrow<Self>,
+                inv_cov: impl Borrow<[[$float; N]; N]>,
+                chi2_thresh: impl Borrow<$float>,
+            ) -> bool {
+                self.squared_mahalanobis(other.borrow(), inv_cov.borrow()) <= *chi2_thresh.borrow()
+            }
+        }
+    )+};
+}
+
+impl_mahalanobis_is_close!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IsClose;
+
+    #[test]
+    fn f64_diagonal_is_close_mahalanobis() {
+        // Standard deviations of 0.1, 1 and 10
+        let inv_cov = [[100.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.01]];
+        let estimate = [1.0, 2.0, 3.0];
+
+        assert!(estimate
+            .squared_mahalanobis(&[1.1, 3.0, 13.0], &inv_cov)
+            .is_close(3.0));
+
+        // 7.815 is the 95% quantile of the chi-squared distribution with 3
+        // degrees of freedom
+        assert!(estimate.is_close_mahalanobis([1.1, 3.0, 13.0], inv_cov, 7.815));
+        assert!(estimate.is_close_mahalanobis([1.0, 2.0, 25.0], inv_cov, 7.815));
+        assert!(!estimate.is_close_mahalanobis([1.3, 2.0, 3.0], inv_cov, 7.815));
+        assert!(!estimate.is_close_mahalanobis([f64::NAN, 2.0, 3.0], inv_cov, 7.815));
+    }
+
+    #[test]
+    fn f64_correlated_is_close_mahalanobis() {
+        // Unit variances with a correlation of 0.9, so the inverse is
+        // [[1, -0.9], [-0.9, 1]] / (1 - 0.81)
+        let det = 0.19;
+        let inv_cov = [[1.0 / det, -0.9 / det], [-0.9 / det, 1.0 / det]];
+        let estimate = [0.0, 0.0];
+
+        // The same Euclidean distance along and across the correlation
+        let along = [1.0, 1.0];
+        let across = [1.0, -1.0];
+
+        assert!(estimate
+            .squared_mahalanobis(&along, &inv_cov)
+            .is_close(2.0 / 1.9));
+        assert!(estimate
+            .squared_mahalanobis(&across, &inv_cov)
+            .is_close(2.0 / 0.1));
+        assert!(estimate.is_close_mahalanobis(along, inv_cov, 5.991));
+        assert!(!estimate.is_close_mahalanobis(across, inv_cov, 5.991));
+    }
+
+    #[test]
+    fn f32_is_close_mahalanobis() {
+        let inv_cov = [[4.0_f32]];
+
+        assert!([1.0_f32].is_close_mahalanobis([1.5], inv_cov, 1.0));
+        assert!(![1.0_f32].is_close_mahalanobis([1.6], inv_cov, 1.0));
+    }
+}
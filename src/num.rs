@@ -0,0 +1,55 @@
+use core::borrow::Borrow;
+
+use num_traits::Float;
+
+use crate::IsClose;
+
+/// Adaptor implementing [`IsClose`] for any type implementing
+/// [`num_traits::Float`]
+///
+/// Since the tolerances must be constants, they are given as [`f64`] values
+/// and converted to `T` when comparing. The default tolerances are the same as
+/// for [`f32`], so lower precision types may need explicit tolerances.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NumFloat<T>(pub T);
+
+impl<T> IsClose<f64> for NumFloat<T>
+where
+    T: Float,
+{
+    const ABS_TOL: f64 = 1e-6;
+    const REL_TOL: f64 = 1e-6;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<f64>,
+        abs_tol: impl Borrow<f64>,
+    ) -> bool {
+        let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        let (rel_tol, abs_tol) = (
+            T::from(*rel_tol).unwrap_or_else(T::nan),
+            T::from(*abs_tol).unwrap_or_else(T::nan),
+        );
+        let tol = self.0.abs().max(other.0.abs()) * rel_tol + abs_tol;
+        (self.0 - other.0).abs() <= tol
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_is_close;
+
+    #[test]
+    fn num_float_is_close_tol() {
+        assert_is_close!(NumFloat(0.1_f32 + 0.2), NumFloat(0.3_f32));
+        assert_is_close!(NumFloat(0.1_f64 + 0.2), NumFloat(0.3_f64));
+
+        assert!(NumFloat(1.0_f32).is_close_tol(NumFloat(1.0 + 1e-2), 1e-1, 0.0));
+        assert!(!NumFloat(1e-2_f32).is_close_tol(NumFloat(1e-2 + 1e-2), 1e-1, 0.0));
+        assert!(NumFloat(1e-2_f64).is_close_tol(NumFloat(1e-2 + 1e-2), 0.0, 1e-1));
+        assert!(!NumFloat(1.0_f64).is_close_tol(NumFloat(1.0 + 1.0), 0.0, 1e-1));
+    }
+}
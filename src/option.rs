@@ -0,0 +1,55 @@
+use core::borrow::Borrow;
+
+use crate::{IsClose, Zero};
+
+/// Compares the values if both are [`Some`], and two [`None`]s are always close
+impl<T, Tolerance> IsClose<Tolerance> for Option<T>
+where
+    T: IsClose<Tolerance>,
+    Tolerance: Zero,
+{
+    const ABS_TOL: Tolerance = T::ABS_TOL;
+    const REL_TOL: Tolerance = T::REL_TOL;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+    ) -> bool {
+        match (self, other.borrow()) {
+            (Some(lhs), Some(rhs)) => lhs.is_close_tol(rhs, rel_tol, abs_tol),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::assert_is_close;
+
+    #[test]
+    fn option_is_close_tol() {
+        assert_is_close!(Some(0.1 + 0.2), Some(0.3));
+        assert_is_close!(None::<f32>, None);
+        assert!(!Some(0.1_f32).is_close(None));
+        assert!(!None.is_close(Some(0.1_f32)));
+        assert!(!Some(0.1_f32).is_close_tol(Some(0.2), 1e-2, 1e-2));
+    }
+
+    #[test]
+    fn option_ref_is_close_tol() {
+        let map1 = HashMap::from([("a", 0.1 + 0.2), ("b", 1.0)]);
+        let map2 = HashMap::from([("a", 0.3), ("c", 1.0)]);
+
+        assert_is_close!(map1.get("a"), map2.get("a"));
+        assert!(map1.get("a").is_close(map2.get("a")));
+        assert!(!map1.get("b").is_close(map2.get("b")));
+        assert!(map1.get("d").is_close(map2.get("d")));
+    }
+}
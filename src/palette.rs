@@ -0,0 +1,173 @@
+//! Additional comparisons for [palette]'s color types
+
+use palette::color_difference::DeltaE;
+use palette::rgb::Rgb;
+use palette::{Alpha, Lab, Xyz};
+
+use crate::{IsClose, Zero};
+
+impl<C, T> IsClose<T> for Alpha<C, T>
+where
+    C: IsClose<T>,
+    T: IsClose<T> + Zero,
+{
+    const ABS_TOL: T = T::ABS_TOL;
+    const REL_TOL: T = T::REL_TOL;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
+            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        self.color.is_close_tol(&other.color, rel_tol, abs_tol)
+            && self.alpha.is_close_tol(&other.alpha, rel_tol, abs_tol)
+    }
+}
+
+impl<Wp, T> IsClose<T> for Lab<Wp, T>
+where
+    T: IsClose<T> + Zero,
+{
+    const ABS_TOL: T = T::ABS_TOL;
+    const REL_TOL: T = T::REL_TOL;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
+            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        self.l.is_close_tol(&other.l, rel_tol, abs_tol)
+            && self.a.is_close_tol(&other.a, rel_tol, abs_tol)
+            && self.b.is_close_tol(&other.b, rel_tol, abs_tol)
+    }
+}
+
+impl<S, T> IsClose<T> for Rgb<S, T>
+where
+    T: IsClose<T> + Zero,
+{
+    const ABS_TOL: T = T::ABS_TOL;
+    const REL_TOL: T = T::REL_TOL;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
+            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        self.red.is_close_tol(&other.red, rel_tol, abs_tol)
+            && self.green.is_close_tol(&other.green, rel_tol, abs_tol)
+            && self.blue.is_close_tol(&other.blue, rel_tol, abs_tol)
+    }
+}
+
+impl<Wp, T> IsClose<T> for Xyz<Wp, T>
+where
+    T: IsClose<T> + Zero,
+{
+    const ABS_TOL: T = T::ABS_TOL;
+    const REL_TOL: T = T::REL_TOL;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        rel_tol: impl core::borrow::Borrow<T>,
+        abs_tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (other, rel_tol, abs_tol): (&Self, &T, &T) =
+            (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        self.x.is_close_tol(&other.x, rel_tol, abs_tol)
+            && self.y.is_close_tol(&other.y, rel_tol, abs_tol)
+            && self.z.is_close_tol(&other.z, rel_tol, abs_tol)
+    }
+}
+
+/// Perceptual comparisons for [`Lab`] colors
+pub trait LabIsClose<T> {
+    /// Check if two colors are perceptually close, i.e. if the CIE76 color
+    /// difference (Δ*E*) between them is no greater than the given tolerance
+    fn is_close_delta_e(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool;
+}
+
+impl<Wp, T> LabIsClose<T> for Lab<Wp, T>
+where
+    Self: Clone + DeltaE<Scalar = T>,
+    T: PartialOrd,
+{
+    #[inline]
+    fn is_close_delta_e(
+        &self,
+        other: impl core::borrow::Borrow<Self>,
+        tol: impl core::borrow::Borrow<T>,
+    ) -> bool {
+        let (other, tol): (&Self, &T) = (other.borrow(), tol.borrow());
+        self.clone().delta_e(other.clone()) <= *tol
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use palette::{LinSrgba, Srgb, Xyz};
+
+    use super::*;
+    use crate::assert_is_close;
+
+    #[test]
+    fn alpha() {
+        let col1 = LinSrgba::new(0.1 + 0.2, 0.5, 0.6, 0.5);
+        let col2 = LinSrgba::new(0.3, 0.5, 0.6, 0.25 * 2.0);
+
+        assert_is_close!(col1, col2);
+    }
+
+    #[test]
+    fn lab() {
+        let col1: Lab = Lab::new(50.0, 10.0 / 3.0, -20.0);
+        let col2: Lab = Lab::new(50.0, 1.0 / 0.3, -20.0);
+
+        assert_is_close!(col1, col2);
+    }
+
+    #[test]
+    fn lab_delta_e() {
+        let col1: Lab = Lab::new(50.0, 10.0, -20.0);
+        let col2: Lab = Lab::new(51.0, 11.0, -20.0);
+
+        assert!(col1.is_close_delta_e(col2, 2.0));
+        assert!(!col1.is_close_delta_e(col2, 1.0));
+        assert!(!col1.is_close(col2));
+    }
+
+    #[test]
+    fn rgb() {
+        let col1 = Srgb::new(0.1 + 0.2, 0.2 + 0.4, 0.7);
+        let col2 = Srgb::new(0.3, 0.6, 0.7);
+
+        assert_is_close!(col1, col2);
+        assert!(!col1.is_close(Srgb::new(0.3, 0.6, 0.71)));
+    }
+
+    #[test]
+    fn xyz() {
+        let col1: Xyz = Xyz::new(0.1 + 0.2, 0.5, 0.2 + 0.4);
+        let col2: Xyz = Xyz::new(0.3, 0.5, 0.6);
+
+        assert_is_close!(col1, col2);
+    }
+}
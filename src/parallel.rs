@@ -0,0 +1,89 @@
+use core::borrow::Borrow;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use rayon::prelude::*;
+
+use crate::{IsClose, Zero};
+
+/// The number of elements compared by each task
+const CHUNK_SIZE: usize = 4096;
+
+/// Check if two slices are approximately equal element-wise using the given
+/// relative and absolute tolerances, comparing chunks in parallel using
+/// [`rayon`]
+///
+/// This gives the same result as [`IsClose::is_close_tol`] for slices, and
+/// slices of different lengths are never close. Once any chunk finds a pair of
+/// elements which are not close, the remaining chunks are skipped.
+///
+/// The result is deterministic, but the number of elements which are compared
+/// before returning is not, so [`IsClose`] implementations should not have
+/// side effects.
+///
+/// ```
+/// use isclose::par_all_close;
+///
+/// let a: Vec<f64> = (0..100_000).map(|i| f64::from(i) * 0.1).collect();
+/// let b: Vec<f64> = (0..100_000).map(|i| f64::from(i) / 10.0).collect();
+///
+/// assert!(par_all_close(&a, &b, 1e-9, 0.0));
+/// ```
+#[must_use]
+pub fn par_all_close<Value, Tolerance>(
+    lhs: &[Value],
+    rhs: &[Value],
+    rel_tol: impl Borrow<Tolerance>,
+    abs_tol: impl Borrow<Tolerance>,
+) -> bool
+where
+    Value: IsClose<Tolerance> + Sync,
+    Tolerance: Zero + Sync,
+{
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+
+    let (rel_tol, abs_tol) = (rel_tol.borrow(), abs_tol.borrow());
+    let failed = AtomicBool::new(false);
+    lhs.par_chunks(CHUNK_SIZE)
+        .zip(rhs.par_chunks(CHUNK_SIZE))
+        .for_each(|(lhs, rhs)| {
+            // Relaxed is fine since the flag is only used to skip work early;
+            // rayon synchronizes with the caller before returning
+            if failed.load(Ordering::Relaxed) {
+                return;
+            }
+            if !lhs
+                .iter()
+                .zip(rhs)
+                .all(|(lhs, rhs)| lhs.is_close_tol(rhs, rel_tol, abs_tol))
+            {
+                failed.store(true, Ordering::Relaxed);
+            }
+        });
+    !failed.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn par_all_close_large() {
+        let lhs: Vec<f64> = (0..1_000_000).map(|i| f64::from(i) * 0.1).collect();
+        let mut rhs: Vec<f64> = (0..1_000_000).map(|i| f64::from(i) / 10.0).collect();
+
+        assert!(par_all_close(&lhs, &rhs, 1e-9, 0.0));
+        assert_eq!(
+            par_all_close(&lhs, &rhs, 1e-9, 0.0),
+            lhs.is_close_tol(&rhs[..], 1e-9, 0.0),
+        );
+
+        rhs[765_432] += 1e-3;
+        assert!(!par_all_close(&lhs, &rhs, 1e-9, 0.0));
+        assert!(par_all_close(&lhs, &rhs, 0.0, 1e-2));
+
+        assert!(!par_all_close(&lhs, &rhs[1..], 1.0, 1.0));
+        assert!(par_all_close::<f32, f32>(&[], &[], 0.0, 0.0));
+    }
+}
@@ -0,0 +1,81 @@
+use proptest::arbitrary::Arbitrary;
+use proptest::num;
+use proptest::strategy::{Map, Strategy};
+
+use crate::Tolerance;
+
+macro_rules! impl_arbitrary {
+    ($($float:ident),+) => {$(
+        /// Generates tolerances with relative and absolute components which
+        /// are non-negative and finite, i.e. any valid tolerance
+        ///
+        /// This can be used to property test [`IsClose`][crate::IsClose]
+        /// implementations against the tolerance contract.
+        ///
+        /// ```
+        /// use isclose::{IsClose, Tolerance};
+        /// use proptest::prelude::*;
+        ///
+        /// proptest! {
+        #[doc = concat!("    fn reflexive(a in prop::num::", stringify!($float), "::NORMAL, tol in any::<Tolerance<", stringify!($float), ">>()) {")]
+        ///         prop_assert!(a.is_close_tol(a, tol.rel, tol.abs));
+        ///     }
+        /// }
+        /// # reflexive();
+        /// ```
+        impl Arbitrary for Tolerance<$float> {
+            type Parameters = ();
+            type Strategy = Map<(num::$float::Any, num::$float::Any), fn(($float, $float)) -> Self>;
+
+            #[inline]
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                let valid = num::$float::POSITIVE
+                    | num::$float::NORMAL
+                    | num::$float::SUBNORMAL
+                    | num::$float::ZERO;
+                (valid, valid).prop_map(Self::from)
+            }
+        }
+    )+};
+}
+
+impl_arbitrary!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use proptest::num;
+    use proptest::prelude::*;
+
+    use crate::{IsClose, Tolerance};
+
+    fn finite() -> impl Strategy<Value = f64> {
+        num::f64::POSITIVE
+            | num::f64::NEGATIVE
+            | num::f64::NORMAL
+            | num::f64::SUBNORMAL
+            | num::f64::ZERO
+    }
+
+    proptest! {
+        #[test]
+        fn tolerance_is_valid(tol in any::<Tolerance<f64>>()) {
+            prop_assert!(tol.rel.is_finite() && tol.rel >= 0.0);
+            prop_assert!(tol.abs.is_finite() && tol.abs >= 0.0);
+        }
+
+        #[test]
+        fn is_close_reflexive(a in finite(), tol in any::<Tolerance<f64>>()) {
+            prop_assert!(a.is_close_tol(a, tol.rel, tol.abs));
+        }
+
+        #[test]
+        fn is_close_symmetric(a in finite(), b in finite(), tol in any::<Tolerance<f64>>()) {
+            prop_assert_eq!(a.is_close_tol(b, tol.rel, tol.abs), b.is_close_tol(a, tol.rel, tol.abs));
+        }
+
+        #[test]
+        fn f32_is_close_reflexive(a in num::f32::NORMAL, tol in any::<Tolerance<f32>>()) {
+            prop_assert!(a.is_close_tol(a, tol.rel, tol.abs));
+        }
+    }
+}
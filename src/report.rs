@@ -0,0 +1,131 @@
+use core::borrow::Borrow;
+use core::fmt::{self, Debug, Write};
+
+use crate::{IsClose, Zero};
+
+/// Trait for writing the details of a comparison without allocating
+///
+/// This is implemented for all types implementing [`IsClose`] and [`Debug`],
+/// and allows `no_std` users to capture the details of a comparison into a
+/// fixed size buffer for logging.
+pub trait CloseReport<Tolerance = Self>: IsClose<Tolerance>
+where
+    Tolerance: Zero,
+{
+    /// Check if two values are approximately equal using the given relative
+    /// and absolute tolerances, writing the details of the comparison to `out`
+    ///
+    /// Returns whether the values are close.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails, e.g. if a fixed size buffer
+    /// is full.
+    fn write_close_report(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+        out: &mut dyn Write,
+    ) -> Result<bool, fmt::Error>;
+}
+
+impl<Value, Tolerance> CloseReport<Tolerance> for Value
+where
+    Value: IsClose<Tolerance> + Debug + ?Sized,
+    Tolerance: Zero + Debug,
+{
+    #[inline]
+    fn write_close_report(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+        out: &mut dyn Write,
+    ) -> Result<bool, fmt::Error> {
+        let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        let close = self.is_close_tol(other, rel_tol, abs_tol);
+        write!(
+            out,
+            "comparison `left ~= right` {}
+    left: {:?}
+   right: {:?}
+ rel tol: {:?}
+ abs tol: {:?}",
+            if close { "passed" } else { "failed" },
+            self,
+            other,
+            rel_tol,
+            abs_tol,
+        )?;
+        Ok(close)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal fixed size buffer implementing [`Write`]
+    struct Buffer<const N: usize> {
+        buf: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> Buffer<N> {
+        const fn new() -> Self {
+            Self {
+                buf: [0; N],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.buf[..self.len]).unwrap()
+        }
+    }
+
+    impl<const N: usize> Write for Buffer<N> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.buf
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write_close_report() {
+        let mut buf = Buffer::<128>::new();
+        assert_eq!(
+            2.0_f32.write_close_report(3.0, 0.1, 0.0, &mut buf),
+            Ok(false)
+        );
+        assert_eq!(
+            buf.as_str(),
+            "comparison `left ~= right` failed
+    left: 2.0
+   right: 3.0
+ rel tol: 0.1
+ abs tol: 0.0"
+        );
+
+        let mut buf = Buffer::<128>::new();
+        assert_eq!(
+            2.0_f32.write_close_report(2.1, 0.1, 0.0, &mut buf),
+            Ok(true)
+        );
+        assert!(buf
+            .as_str()
+            .starts_with("comparison `left ~= right` passed\n"));
+
+        let mut buf = Buffer::<16>::new();
+        assert_eq!(
+            2.0_f32.write_close_report(3.0, 0.1, 0.0, &mut buf),
+            Err(fmt::Error)
+        );
+    }
+}
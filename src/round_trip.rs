@@ -0,0 +1,93 @@
+use core::borrow::Borrow;
+
+use crate::{IsClose, Zero};
+
+/// Check if a value is approximately equal to itself after round-tripping it
+/// through `encode` and then `decode`, using the given relative and absolute
+/// tolerances
+///
+/// This is intended for testing lossy conversions such as quantization or
+/// serialization to a lower precision format. See also
+/// [`assert_round_trips_close!`][crate::assert_round_trips_close].
+///
+/// ```
+/// use isclose::round_trip_is_close;
+///
+/// // Quantize to 8 bits over the range 0..=1
+/// let encode = |x: &f32| (x * 255.0).round() as u8;
+/// let decode = |q: &u8| f32::from(*q) / 255.0;
+///
+/// assert!(round_trip_is_close(&0.3, encode, decode, 0.0, 0.5 / 255.0));
+/// assert!(!round_trip_is_close(&0.3, encode, decode, 0.0, 1e-4));
+/// ```
+#[inline]
+pub fn round_trip_is_close<T, U, Tolerance>(
+    value: &T,
+    encode: impl Fn(&T) -> U,
+    decode: impl Fn(&U) -> T,
+    rel_tol: impl Borrow<Tolerance>,
+    abs_tol: impl Borrow<Tolerance>,
+) -> bool
+where
+    T: IsClose<Tolerance>,
+    Tolerance: Zero,
+{
+    value.is_close_tol(decode(&encode(value)), rel_tol, abs_tol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::cast_possible_truncation)] // values are in the range of i16
+    fn quantize(x: f64) -> i16 {
+        (x * 1024.0).round() as i16
+    }
+
+    fn dequantize(q: i16) -> f64 {
+        f64::from(q) / 1024.0
+    }
+
+    #[test]
+    fn quantized_round_trip_is_close() {
+        let (encode, decode) = (|x: &f64| quantize(*x), |q: &i16| dequantize(*q));
+        let step = 1.0 / 1024.0;
+
+        for &x in &[0.0, 0.1, -0.3, 1.0 / 3.0, 12.345, -31.999] {
+            assert!(round_trip_is_close(&x, encode, decode, 0.0, step / 2.0));
+        }
+        assert!(!round_trip_is_close(
+            &0.1,
+            encode,
+            decode,
+            0.0,
+            step / 100.0
+        ));
+        assert!(round_trip_is_close(&12.345, encode, decode, 1e-4, 0.0));
+        assert!(!round_trip_is_close(&0.001, encode, decode, 1e-4, 0.0));
+
+        // Exactly representable values round trip exactly
+        assert!(round_trip_is_close(&0.75, encode, decode, 0.0, 0.0));
+    }
+
+    #[test]
+    fn tuple_round_trip_is_close() {
+        let encode = |&(a, b): &(f64, f64)| (quantize(a), quantize(b));
+        let decode = |(a, b): &(i16, i16)| (dequantize(*a), dequantize(*b));
+
+        assert!(round_trip_is_close(
+            &(0.1, 0.2),
+            encode,
+            decode,
+            (0.0, 0.0),
+            (1e-3, 1e-3)
+        ));
+        assert!(!round_trip_is_close(
+            &(0.1, 0.2),
+            encode,
+            decode,
+            (0.0, 0.0),
+            (1e-3, 1e-5)
+        ));
+    }
+}
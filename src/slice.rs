@@ -0,0 +1,173 @@
+use core::borrow::Borrow;
+
+use crate::{IsClose, Zero};
+
+/// Compares the slices element-wise, and slices of different lengths are never
+/// close
+///
+/// Since arrays and [`Vec`]s both borrow as slices, this also allows comparing
+/// arrays, [`Vec`]s, and slices against each other.
+impl<T, Tolerance> IsClose<Tolerance> for [T]
+where
+    T: IsClose<Tolerance>,
+    Tolerance: Zero,
+{
+    const ABS_TOL: Tolerance = T::ABS_TOL;
+    const REL_TOL: Tolerance = T::REL_TOL;
+
+    #[inline]
+    fn is_close_tol(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+    ) -> bool {
+        let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(lhs, rhs)| lhs.is_close_tol(rhs, rel_tol, abs_tol))
+    }
+}
+
+/// Additional comparisons for slices
+pub trait SliceIsClose<Tolerance> {
+    /// Check if two slices are approximately equal element-wise using the
+    /// given relative and absolute tolerances, ignoring the first `skip_front`
+    /// and last `skip_back` elements
+    ///
+    /// This is useful for comparing filtered signals, where samples near the
+    /// boundaries are unreliable while the filter warms up. The slices must
+    /// still have the same length before trimming. If the trimmed elements
+    /// cover the whole slice, there is nothing left to compare, so the slices
+    /// are considered close.
+    fn is_close_trimmed(
+        &self,
+        other: impl Borrow<Self>,
+        skip_front: usize,
+        skip_back: usize,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+    ) -> bool;
+
+    /// The indices of every pair of elements which are not approximately equal
+    /// using the given relative and absolute tolerances
+    ///
+    /// This is useful for custom reporting of failed comparisons. If the
+    /// slices have different lengths, no elements correspond, so every index
+    /// of the longer slice is returned.
+    #[cfg(feature = "alloc")]
+    fn close_failures(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+    ) -> alloc::vec::Vec<usize>;
+}
+
+impl<T, Tolerance> SliceIsClose<Tolerance> for [T]
+where
+    T: IsClose<Tolerance>,
+    Tolerance: Zero,
+{
+    #[inline]
+    fn is_close_trimmed(
+        &self,
+        other: impl Borrow<Self>,
+        skip_front: usize,
+        skip_back: usize,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+    ) -> bool {
+        let other = other.borrow();
+        if self.len() != other.len() {
+            return false;
+        }
+        let end = self.len().saturating_sub(skip_back);
+        let start = skip_front.min(end);
+        self[start..end].is_close_tol(&other[start..end], rel_tol, abs_tol)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn close_failures(
+        &self,
+        other: impl Borrow<Self>,
+        rel_tol: impl Borrow<Tolerance>,
+        abs_tol: impl Borrow<Tolerance>,
+    ) -> alloc::vec::Vec<usize> {
+        let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+        if self.len() != other.len() {
+            return (0..self.len().max(other.len())).collect();
+        }
+        self.iter()
+            .zip(other)
+            .enumerate()
+            .filter(|(_, (lhs, rhs))| !lhs.is_close_tol(*rhs, rel_tol, abs_tol))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_is_close;
+
+    #[test]
+    fn slice_is_close_tol() {
+        let computed: Vec<f64> = [0.1 + 0.2, 0.2 + 0.4, 1.0 / 3.0].to_vec();
+
+        assert!(computed.is_close([0.3, 0.6, 0.333_333_333_333]));
+        assert!([0.3, 0.6, 0.333_333_333_333].is_close(&computed[..]));
+        assert!(computed[..2].is_close([0.3, 0.6]));
+        assert!(!computed.is_close([0.3, 0.6]));
+        assert!(!computed.is_close([0.3, 0.6, 0.3]));
+        assert!(computed.is_close_abs_tol([0.3, 0.6, 0.3], 0.1));
+
+        assert_is_close!(computed[..], [0.3, 0.6, 0.333_333_333_333]);
+    }
+
+    #[test]
+    fn slice_is_close_trimmed() {
+        // A filter's output before it has warmed up at both ends
+        let filtered = [0.0, 0.5, 0.98, 1.0, 1.01, 0.99, 0.6, 0.1];
+        let expected = [1.0; 8];
+
+        assert!(!filtered.is_close_abs_tol(expected, 0.05));
+        assert!(filtered.is_close_trimmed(&expected[..], 2, 2, 0.0, 0.05));
+        assert!(!filtered.is_close_trimmed(&expected[..], 1, 2, 0.0, 0.05));
+        assert!(!filtered.is_close_trimmed(&expected[..], 2, 1, 0.0, 0.05));
+
+        // Lengths must match before trimming
+        assert!(!filtered.is_close_trimmed(&expected[..7], 2, 2, 0.0, 0.05));
+        assert!(!filtered[..7].is_close_trimmed(&expected[..], 2, 2, 0.0, 0.05));
+
+        assert!(filtered.is_close_trimmed(&expected[..], 4, 4, 0.0, 0.0));
+        assert!(filtered.is_close_trimmed(&expected[..], 10, 10, 0.0, 0.0));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn slice_close_failures() {
+        let computed = [0.1 + 0.2, 0.5, 0.7, 1.0 / 3.0, 2.0, f64::NAN];
+        let expected = [0.3, 0.6, 0.7, 0.333_333_333_333, 2.1, 0.0];
+
+        assert_eq!(computed.close_failures(&expected[..], 1e-9, 0.0), [1, 4, 5]);
+        assert_eq!(computed.close_failures(&expected[..], 1e-9, 0.2), [5]);
+        assert!(computed[..3]
+            .close_failures(&[0.3, 0.5, 0.7][..], 1e-9, 0.0)
+            .is_empty());
+
+        // Different lengths
+        assert_eq!(
+            computed[..2].close_failures(&expected[..3], 1.0, 1.0),
+            [0, 1, 2]
+        );
+        assert_eq!(
+            computed[..3].close_failures(&expected[..2], 1.0, 1.0),
+            [0, 1, 2]
+        );
+    }
+}
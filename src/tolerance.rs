@@ -0,0 +1,182 @@
+use core::borrow::Borrow;
+
+use crate::{IsClose, Zero};
+
+/// A pair of relative and absolute tolerances
+///
+/// Named tolerances can be declared as constants using the [`tol!`] macro and
+/// used with the `named = ...` form of [`assert_is_close!`].
+///
+/// [`tol!`]: crate::tol
+/// [`assert_is_close!`]: crate::assert_is_close
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tolerance<T> {
+    /// The relative tolerance
+    pub rel: T,
+    /// The absolute tolerance
+    pub abs: T,
+}
+
+impl<T> Tolerance<T> {
+    /// Create a new tolerance from the given relative and absolute tolerances
+    #[inline]
+    pub const fn new(rel: T, abs: T) -> Self {
+        Self { rel, abs }
+    }
+}
+
+/// Uses the default tolerances of `T`'s [`IsClose`] implementation
+impl<T> Default for Tolerance<T>
+where
+    T: IsClose + Zero,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::REL_TOL, T::ABS_TOL)
+    }
+}
+
+/// Creates a tolerance from a tuple of `(rel, abs)` tolerances
+impl<T> From<(T, T)> for Tolerance<T> {
+    #[inline]
+    fn from((rel, abs): (T, T)) -> Self {
+        Self::new(rel, abs)
+    }
+}
+
+/// Whether all or any of the tolerances of a [`CompositeTolerance`] must pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Combinator {
+    All,
+    Any,
+}
+
+/// A combination of several tolerances, created by [`all_of`] or [`any_of`]
+///
+/// Each tolerance is evaluated as with [`IsClose::is_close_tol`], and the
+/// results are combined using the given logic. This generalizes the built-in
+/// formula, which sums the relative and absolute terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompositeTolerance<T, const N: usize> {
+    tols: [Tolerance<T>; N],
+    combinator: Combinator,
+}
+
+/// Combine tolerances so that values are only close if they are close under
+/// every one of them
+///
+/// With no tolerances, any values are considered close.
+///
+/// ```
+/// use isclose::{all_of, Tolerance};
+///
+/// let tol = all_of([Tolerance::new(1e-3, 0.0), Tolerance::new(0.0, 1e-3)]);
+///
+/// assert!(tol.is_close(&0.5, 0.5001));
+/// assert!(!tol.is_close(&5.0, 5.001));
+/// ```
+#[inline]
+pub const fn all_of<T, const N: usize>(tols: [Tolerance<T>; N]) -> CompositeTolerance<T, N> {
+    CompositeTolerance {
+        tols,
+        combinator: Combinator::All,
+    }
+}
+
+/// Combine tolerances so that values are close if they are close under at
+/// least one of them
+///
+/// With no tolerances, values are never considered close.
+///
+/// ```
+/// use isclose::{any_of, Tolerance};
+///
+/// // A strict relative check, or a loose absolute check
+/// let tol = any_of([Tolerance::new(1e-9, 0.0), Tolerance::new(0.0, 1e-3)]);
+///
+/// assert!(tol.is_close(&1e9, 1e9 + 0.5));
+/// assert!(tol.is_close(&0.0, 1e-4));
+/// assert!(!tol.is_close(&1.0, 1.1));
+/// ```
+#[inline]
+pub const fn any_of<T, const N: usize>(tols: [Tolerance<T>; N]) -> CompositeTolerance<T, N> {
+    CompositeTolerance {
+        tols,
+        combinator: Combinator::Any,
+    }
+}
+
+impl<T, const N: usize> CompositeTolerance<T, N>
+where
+    T: Zero,
+{
+    /// Check if two values are approximately equal under the combined
+    /// tolerances
+    #[inline]
+    pub fn is_close<Value>(&self, lhs: &Value, rhs: impl Borrow<Value>) -> bool
+    where
+        Value: IsClose<T> + ?Sized,
+    {
+        let rhs = rhs.borrow();
+        let mut results = self
+            .tols
+            .iter()
+            .map(|tol| lhs.is_close_tol(rhs, &tol.rel, &tol.abs));
+        match self.combinator {
+            Combinator::All => results.all(|result| result),
+            Combinator::Any => results.any(|result| result),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tolerance_default() {
+        let tol: Tolerance<f32> = Tolerance::default();
+        assert_eq!(tol, Tolerance::new(f32::REL_TOL, f32::ABS_TOL));
+
+        let tol: Tolerance<f64> = Tolerance::default();
+        assert_eq!(tol, Tolerance::new(f64::REL_TOL, f64::ABS_TOL));
+    }
+
+    #[test]
+    fn tolerance_from() {
+        let tol: Tolerance<f64> = (1e-6, 1e-9).into();
+        assert_eq!(tol, Tolerance::new(1e-6, 1e-9));
+    }
+
+    #[test]
+    fn composite_any_of() {
+        let strict_rel = Tolerance::new(1e-9, 0.0);
+        let loose_abs = Tolerance::new(0.0, 1e-3);
+        let tol = any_of([strict_rel, loose_abs]);
+
+        // Only the relative criterion holds
+        assert!(1e9.is_close_tol(1e9 + 0.5, strict_rel.rel, strict_rel.abs));
+        assert!(!1e9.is_close_tol(1e9 + 0.5, loose_abs.rel, loose_abs.abs));
+        assert!(tol.is_close(&1e9, 1e9 + 0.5));
+        // Only the absolute criterion holds
+        assert!(!0.0.is_close_tol(1e-4, strict_rel.rel, strict_rel.abs));
+        assert!(0.0.is_close_tol(1e-4, loose_abs.rel, loose_abs.abs));
+        assert!(tol.is_close(&0.0, 1e-4));
+        // Neither holds
+        assert!(!tol.is_close(&1.0, 1.1));
+
+        assert!(!any_of::<f64, 0>([]).is_close(&1.0, 1.0));
+    }
+
+    #[test]
+    fn composite_all_of() {
+        let tol = all_of([Tolerance::new(1e-3, 0.0), Tolerance::new(0.0, 1e-3)]);
+
+        assert!(tol.is_close(&0.5_f32, 0.5001));
+        assert!(!tol.is_close(&0.1_f32, 0.1009));
+        assert!(!tol.is_close(&5.0_f32, 5.002));
+        assert!(tol.is_close(&[0.1, 0.2][..], [0.1, 0.2 + 1e-6]));
+
+        assert!(all_of::<f64, 0>([]).is_close(&1.0, 2.0));
+    }
+}
@@ -0,0 +1,104 @@
+use core::borrow::Borrow;
+
+use crate::{HasZero, IsClose, Zero};
+
+// Tuples of tolerances allow types with fields of different precisions to use
+// separate tolerances, and the default tolerances, for each field, e.g. a type
+// with an f32 field and an f64 field can implement IsClose<(f32, f64)>. Tuples
+// themselves are compared element-wise using the tolerances for each element.
+
+macro_rules! impl_tuple {
+    ($(($($idx:tt $name:ident $tol:ident),+)),+) => {$(
+        impl<$($tol: Zero),+> Zero for ($($tol,)+) {
+            const ZERO: Self = ($($tol::ZERO,)+);
+        }
+
+        impl<$($name: HasZero),+> HasZero for ($($name,)+) {
+            #[inline]
+            fn zero() -> Self {
+                ($($name::zero(),)+)
+            }
+        }
+
+        impl<$($name, $tol),+> IsClose<($($tol,)+)> for ($($name,)+)
+        where
+            $($name: IsClose<$tol>, $tol: Zero,)+
+        {
+            const ABS_TOL: ($($tol,)+) = ($(<$name as IsClose<$tol>>::ABS_TOL,)+);
+            const REL_TOL: ($($tol,)+) = ($(<$name as IsClose<$tol>>::REL_TOL,)+);
+
+            #[inline]
+            fn is_close_tol(
+                &self,
+                other: impl Borrow<Self>,
+                rel_tol: impl Borrow<($($tol,)+)>,
+                abs_tol: impl Borrow<($($tol,)+)>,
+            ) -> bool {
+                let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+                true $(&& self.$idx.is_close_tol(&other.$idx, &rel_tol.$idx, &abs_tol.$idx))+
+            }
+        }
+    )+};
+}
+
+impl_tuple!(
+    (0 A TA),
+    (0 A TA, 1 B TB),
+    (0 A TA, 1 B TB, 2 C TC),
+    (0 A TA, 1 B TB, 2 C TC, 3 D TD),
+    (0 A TA, 1 B TB, 2 C TC, 3 D TD, 4 E TE),
+    (0 A TA, 1 B TB, 2 C TC, 3 D TD, 4 E TE, 5 F TF),
+    (0 A TA, 1 B TB, 2 C TC, 3 D TD, 4 E TE, 5 F TF, 6 G TG),
+    (0 A TA, 1 B TB, 2 C TC, 3 D TD, 4 E TE, 5 F TF, 6 G TG, 7 H TH),
+    (0 A TA, 1 B TB, 2 C TC, 3 D TD, 4 E TE, 5 F TF, 6 G TG, 7 H TH, 8 I TI),
+    (0 A TA, 1 B TB, 2 C TC, 3 D TD, 4 E TE, 5 F TF, 6 G TG, 7 H TH, 8 I TI, 9 J TJ),
+    (0 A TA, 1 B TB, 2 C TC, 3 D TD, 4 E TE, 5 F TF, 6 G TG, 7 H TH, 8 I TI, 9 J TJ, 10 K TK),
+    (0 A TA, 1 B TB, 2 C TC, 3 D TD, 4 E TE, 5 F TF, 6 G TG, 7 H TH, 8 I TI, 9 J TJ, 10 K TK, 11 L TL)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Mixed {
+        a: f32,
+        b: f64,
+    }
+
+    impl IsClose<(f32, f64)> for Mixed {
+        const ABS_TOL: (f32, f64) = (f32::ABS_TOL, f64::ABS_TOL);
+        const REL_TOL: (f32, f64) = (f32::REL_TOL, f64::REL_TOL);
+
+        fn is_close_tol(
+            &self,
+            other: impl Borrow<Self>,
+            rel_tol: impl Borrow<(f32, f64)>,
+            abs_tol: impl Borrow<(f32, f64)>,
+        ) -> bool {
+            let (other, rel_tol, abs_tol) = (other.borrow(), rel_tol.borrow(), abs_tol.borrow());
+            self.a.is_close_tol(other.a, rel_tol.0, abs_tol.0)
+                && self.b.is_close_tol(other.b, rel_tol.1, abs_tol.1)
+        }
+    }
+
+    #[test]
+    fn mixed_tolerance() {
+        let mixed = |a, b| Mixed { a, b };
+        let lhs = mixed(1.0, 1.0);
+
+        assert!(lhs.is_close(mixed(1.0 + 1e-7, 1.0 + 1e-10)));
+        assert!(!lhs.is_close(mixed(1.0 + 1e-7, 1.0 + 1e-7)));
+        assert!(!lhs.is_close(mixed(1.0 + 1e-5, 1.0)));
+        assert!(lhs.is_close_rel_tol(mixed(1.0 + 1e-5, 1.0 + 1e-5), (1e-4, 1e-4)));
+        assert!(!lhs.is_close_rel_tol(mixed(1.0 + 1e-5, 1.0 + 1e-5), (1e-4, 1e-6)));
+    }
+
+    #[test]
+    fn tuple_is_close_tol() {
+        assert!((0.1_f32 + 0.2, 0.1_f64 + 0.2).is_close((0.3, 0.3)));
+        assert!((1.0_f32, 1.0_f64).is_close((1.0 + 1e-7, 1.0 + 1e-10)));
+        assert!(!(1.0_f32, 1.0_f64).is_close((1.0 + 1e-7, 1.0 + 1e-7)));
+        assert!(!(1.0_f32, 1.0_f64).is_close((1.0 + 1e-5, 1.0)));
+        assert!((1.0_f32, 1.0_f64).is_close_abs_tol((1.1, 1.0 + 1e-3), (0.2, 1e-2)));
+    }
+}
@@ -0,0 +1,82 @@
+use core::borrow::Borrow;
+
+use crate::{IsClose, Zero};
+
+/// Trait for types which have an additive identity
+///
+/// Unlike [`Zero`], which provides a constant zero tolerance, this is
+/// implemented for composite values such as tuples and [euclid][::euclid]'s
+/// vectors and matrices, whose zero can't always be built in a constant
+/// context. Composite types delegate to the zero of each component.
+pub trait HasZero {
+    /// The additive identity
+    fn zero() -> Self;
+}
+
+/// Trait for checking if a value is approximately equal to its additive
+/// identity
+///
+/// This is implemented for every type that implements both [`IsClose`] and
+/// [`HasZero`]. Only the absolute tolerance is used, since any relative
+/// tolerance of zero is zero.
+///
+/// ```
+/// use isclose::IsCloseToZero;
+///
+/// assert!((0.1 + 0.2 - 0.3).is_close_to_zero(1e-9));
+/// assert!((1e-4_f32, 1e-10_f64).is_close_to_zero((1e-3, 1e-9)));
+/// assert!(!(1e-4_f32, 1e-8_f64).is_close_to_zero((1e-3, 1e-9)));
+/// ```
+pub trait IsCloseToZero<Tolerance = Self>
+where
+    Tolerance: Zero,
+{
+    /// Check if the value is approximately equal to [`HasZero::zero`] using
+    /// the given absolute tolerance
+    fn is_close_to_zero(&self, abs_tol: impl Borrow<Tolerance>) -> bool;
+}
+
+impl<T, Tolerance> IsCloseToZero<Tolerance> for T
+where
+    T: IsClose<Tolerance> + HasZero,
+    Tolerance: Zero,
+{
+    #[inline]
+    fn is_close_to_zero(&self, abs_tol: impl Borrow<Tolerance>) -> bool {
+        self.is_close_abs_tol(Self::zero(), abs_tol)
+    }
+}
+
+impl HasZero for f32 {
+    #[inline]
+    fn zero() -> Self {
+        0.0
+    }
+}
+
+impl HasZero for f64 {
+    #[inline]
+    fn zero() -> Self {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_is_close_to_zero() {
+        assert!((0.1_f32 + 0.2 - 0.3).is_close_to_zero(1e-6));
+        assert!(0.0_f64.is_close_to_zero(0.0));
+        assert!((-1e-10_f64).is_close_to_zero(1e-9));
+        assert!(!1e-8_f64.is_close_to_zero(1e-9));
+        assert!(!f64::NAN.is_close_to_zero(f64::INFINITY));
+    }
+
+    #[test]
+    fn tuple_is_close_to_zero() {
+        assert!((1e-7_f32, -1e-10_f64).is_close_to_zero((1e-6, 1e-9)));
+        assert!(!(1e-7_f32, -1e-8_f64).is_close_to_zero((1e-6, 1e-9)));
+    }
+}
@@ -0,0 +1,44 @@
+//! Checks that the `ISCLOSE_REL_TOL` and `ISCLOSE_ABS_TOL` environment
+//! variables override the default tolerances
+//!
+//! The variables are only read once per process, so this is kept in its own
+//! test binary with a single test that sets them before any assertion.
+
+#![cfg(feature = "env-tol")]
+
+use isclose::{assert_is_close, assert_is_close_min, close_matches, closeness, IsClose};
+
+#[derive(Debug)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[test]
+fn env_tol() {
+    std::env::set_var("ISCLOSE_REL_TOL", "1e-3");
+    std::env::set_var("ISCLOSE_ABS_TOL", "1e-6");
+
+    // Not close with the built-in default tolerances...
+    assert!(!1.0_f64.is_close(1.0005));
+    assert!(!0.0_f64.is_close(5e-7));
+
+    // ...but close with the tolerances from the environment
+    assert_is_close!(1.0_f64, 1.0005);
+    assert_is_close!(0.0_f64, 5e-7);
+    assert_is_close!(1.0_f32, 1.0005);
+    assert_is_close_min!(1.0_f64, 1.0005);
+    closeness(&1.0_f64, &1.0005).assert();
+    let point = Point { x: 1.0, y: 2.0 };
+    assert!(close_matches!(
+        point,
+        Point {
+            x: 1.0005,
+            y: 2.001
+        }
+    ));
+
+    // Values outside the overridden tolerances still fail
+    assert!(!closeness(&1.0_f64, &1.01).check());
+    assert!(std::panic::catch_unwind(|| assert_is_close!(1.0_f64, 1.01)).is_err());
+}
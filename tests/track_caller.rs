@@ -0,0 +1,59 @@
+//! Checks that assertion failures are reported at the caller's location
+//!
+//! This replaces the global panic hook, so it is kept in its own test binary
+//! with a single test to avoid interfering with any other tests.
+
+#![cfg(feature = "std")]
+
+use std::cell::RefCell;
+use std::panic::{self, UnwindSafe};
+
+use isclose::{assert_is_close, closeness, try_assert_is_close};
+
+std::thread_local! {
+    static LOCATION: RefCell<Option<(String, u32)>> = const { RefCell::new(None) };
+}
+
+/// Returns the location reported by the panic of the given function
+fn panic_location(f: impl FnOnce() + UnwindSafe) -> Option<(String, u32)> {
+    let result = panic::catch_unwind(f);
+    assert!(result.is_err());
+    LOCATION.with(|location| location.borrow_mut().take())
+}
+
+#[track_caller]
+fn assert_is_close_helper(lhs: f32, rhs: f32) {
+    assert_is_close!(lhs, rhs);
+}
+
+#[track_caller]
+fn closeness_helper(lhs: f32, rhs: f32) {
+    closeness(&lhs, &rhs).assert();
+}
+
+#[track_caller]
+fn try_assert_is_close_helper(lhs: f32, rhs: f32) {
+    try_assert_is_close!(lhs, rhs).unwrap();
+}
+
+#[test]
+fn caller_location() {
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().unwrap();
+        LOCATION.with(|l| *l.borrow_mut() = Some((location.file().into(), location.line())));
+    }));
+
+    let line = line!() + 1;
+    let assert_is_close = panic_location(|| assert_is_close_helper(2.0, 3.0));
+    let closeness_line = line!() + 1;
+    let closeness = panic_location(|| closeness_helper(2.0, 3.0));
+    let try_line = line!() + 1;
+    let try_assert_is_close = panic_location(|| try_assert_is_close_helper(2.0, 3.0));
+
+    panic::set_hook(prev_hook);
+
+    assert_eq!(assert_is_close, Some((file!().into(), line)));
+    assert_eq!(closeness, Some((file!().into(), closeness_line)));
+    assert_eq!(try_assert_is_close, Some((file!().into(), try_line)));
+}